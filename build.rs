@@ -0,0 +1,59 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fmt::Write, fs, path::Path};
+
+/// Generates the registry of solved days used by the all-days runner.
+/// Every `src/bin/YYYY-NN.rs` is included as module `yYYYY_dayNN` and its `Solution` is added to `DAYS`.
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src")
+        .join("bin");
+
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .expect("could not read src/bin")
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let (year, day) = file_name.strip_suffix(".rs")?.split_once('-')?;
+            let is_digits = |s: &str, len| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+            (is_digits(year, 4) && is_digits(day, 2)).then(|| (year.to_string(), day.to_string()))
+        })
+        .collect();
+
+    days.sort();
+
+    let mut registry = String::new();
+
+    for (year, day) in &days {
+        let path = bin_dir.join(format!("{year}-{day}.rs"));
+        writeln!(registry, "#[cfg(not(test))]").unwrap();
+        writeln!(registry, "#[allow(dead_code)]").unwrap();
+        writeln!(registry, "#[path = {:?}]", path.display().to_string()).unwrap();
+        writeln!(registry, "mod y{year}_day{day};").unwrap();
+    }
+
+    let entries: Vec<String> = days
+        .iter()
+        .map(|(year, day)| format!("&y{year}_day{day}::Solution"))
+        .collect();
+
+    writeln!(registry, "#[cfg(not(test))]").unwrap();
+    writeln!(
+        registry,
+        "pub const DAYS: &[&dyn advent_of_code::Solution] = &[{}];",
+        entries.join(", ")
+    )
+    .unwrap();
+    writeln!(registry, "#[cfg(test)]").unwrap();
+    writeln!(
+        registry,
+        "pub const DAYS: &[&dyn advent_of_code::Solution] = &[];"
+    )
+    .unwrap();
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, registry).expect("could not write day registry");
+}
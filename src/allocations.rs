@@ -0,0 +1,117 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Heap usage of a measured function.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak_bytes: u64,
+}
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps the system allocator and counts allocations. Installed as the global allocator
+/// when the `allocations` feature is enabled.
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    // a reallocation counts as a new allocation of `new_size` bytes.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `func` and returns the heap usage it caused, or `None` if the `allocations` feature is
+/// disabled. The counters are process-wide, so other threads allocating at the same time skew them.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if cfg!(not(feature = "allocations")) {
+        return (func(), None);
+    }
+
+    let live_before = LIVE_BYTES.load(Ordering::SeqCst);
+    PEAK_BYTES.store(live_before, Ordering::SeqCst);
+    let allocations_before = ALLOCATIONS.load(Ordering::SeqCst);
+    let bytes_before = BYTES.load(Ordering::SeqCst);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::SeqCst) - allocations_before,
+        bytes: BYTES.load(Ordering::SeqCst) - bytes_before,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::SeqCst)
+            .saturating_sub(live_before),
+    };
+
+    (result, Some(stats))
+}
+
+#[cfg(all(test, feature = "allocations"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (sum, stats) = measure(|| {
+            let first: Vec<u64> = vec![1; 1000];
+            let second: Vec<u64> = vec![2; 500];
+            first.iter().chain(second.iter()).sum::<u64>()
+        });
+
+        assert_eq!(sum, 2000);
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 12_000);
+        assert!(stats.peak_bytes >= 12_000);
+    }
+}
@@ -0,0 +1,179 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::PartResult;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// The known correct answers of every solved day by year, as stored in `answers.toml`:
+///
+/// ```toml
+/// [2022.day01]
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Answers> {
+        toml::from_str(contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents =
+            toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        let answers = self.years.get(&year.to_string())?.get(&day_key(day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: &str) {
+        let answers = self
+            .years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day_key(day))
+            .or_default();
+        match part {
+            1 => answers.part1 = Some(answer.into()),
+            2 => answers.part2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    /// Compares a run of `year` against the known answers. Parts without a known answer are skipped.
+    pub fn check(&self, year: u16, results: &[PartResult]) -> Vec<Mismatch> {
+        results
+            .iter()
+            .filter_map(|result| {
+                let expected = self.get(year, result.day, result.part)?;
+                if result.answer.as_deref() == Some(expected) {
+                    None
+                } else {
+                    Some(Mismatch {
+                        year,
+                        day: result.day,
+                        part: result.part,
+                        expected: expected.into(),
+                        actual: result.answer.clone(),
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// A part whose answer differs from the one recorded in `answers.toml`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} day {:02}, part {}:", self.year, self.day, self.part)?;
+        writeln!(f, "- {}", self.expected)?;
+        match &self.actual {
+            Some(actual) => write!(f, "+ {actual}"),
+            None => write!(f, "+ (not solved)"),
+        }
+    }
+}
+
+/// Records a correct answer in `answers.toml`.
+pub fn record(year: u16, day: u8, part: u8, answer: &str) -> io::Result<()> {
+    let path = Path::new(ANSWERS_PATH);
+    let mut answers = Answers::load(path)?;
+    answers.set(year, day, part, answer);
+    answers.save(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn result(day: u8, part: u8, answer: Option<&str>) -> PartResult {
+        PartResult::new(2022, day, part, answer.map(String::from), Duration::ZERO)
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(2022, 5, 1, "CMZ");
+        answers.set(2022, 1, 2, "45000");
+        answers.set(2022, 1, 1, "24000");
+        answers.set(2015, 1, 1, "138");
+
+        let contents = toml::to_string(&answers).unwrap();
+        assert_eq!(
+            contents,
+            "[2015.day01]\npart1 = \"138\"\n\n[2022.day01]\npart1 = \"24000\"\npart2 = \"45000\"\n\n[2022.day05]\npart1 = \"CMZ\"\n"
+        );
+        assert_eq!(Answers::parse(&contents).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers =
+            Answers::parse("[2022.day01]\npart1 = \"24000\"\npart2 = \"45000\"\n").unwrap();
+
+        let results = [
+            result(1, 1, Some("24000")),
+            result(1, 2, Some("45001")),
+            result(2, 1, Some("15")),
+        ];
+        assert_eq!(
+            answers.check(2022, &results),
+            vec![Mismatch {
+                year: 2022,
+                day: 1,
+                part: 2,
+                expected: "45000".into(),
+                actual: Some("45001".into()),
+            }]
+        );
+
+        let unsolved = [result(1, 1, None)];
+        assert_eq!(answers.check(2022, &unsolved)[0].actual, None);
+        assert!(answers.check(2015, &results).is_empty());
+    }
+}
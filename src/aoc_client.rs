@@ -0,0 +1,581 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::day_path;
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// Sent unless `AOC_USER_AGENT` is set. Advent of Code asks automated tools to name where they
+/// come from, so set it to e.g. your repository's URL and your email.
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `ADVENT_OF_CODE_SESSION` nor the session file is set.
+    SessionNotFound(PathBuf),
+    /// The server rejected the session cookie.
+    Unauthorized,
+    /// The page does not exist (yet), e.g. a puzzle that is not unlocked.
+    NotFound(String),
+    /// The server answered with an unexpected status.
+    BadStatus(u16, String),
+    /// The server could not be reached.
+    Network(String),
+    IoError(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::SessionNotFound(path) => write!(
+                f,
+                "no session cookie found. Paste it into \"{}\" or set ADVENT_OF_CODE_SESSION.",
+                path.display()
+            ),
+            ClientError::Unauthorized => write!(
+                f,
+                "the session cookie was rejected, it might have expired. Refresh your \"{SESSION_FILE}\" file."
+            ),
+            ClientError::NotFound(url) => {
+                write!(f, "\"{url}\" was not found, is the puzzle unlocked yet?")
+            }
+            ClientError::BadStatus(status, url) => {
+                write!(f, "\"{url}\" responded with status {status}.")
+            }
+            ClientError::Network(e) => write!(f, "could not reach Advent of Code: {e}"),
+            ClientError::IoError(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+impl ClientError {
+    /// Whether the request might succeed when retried, e.g. a puzzle that is about to unlock.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ClientError::NotFound(_) | ClientError::Network(_) => true,
+            ClientError::BadStatus(status, _) => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// When the puzzle of `day` unlocks: midnight US-Eastern (UTC-5 in December).
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // days since 1970-01-01 of December `day`, see http://howardhinnant.github.io/date_algorithms.html
+    let year = u64::from(year);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let day_of_year = (153 * 9 + 2) / 5 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    SystemTime::UNIX_EPOCH + Duration::from_secs(days * 86_400 + 5 * 3_600)
+}
+
+/// The path of the session file in the home directory.
+pub fn session_path() -> PathBuf {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(SESSION_FILE)
+}
+
+/// Reads the session cookie from `ADVENT_OF_CODE_SESSION` or else from `path`.
+pub fn read_session(path: &Path) -> Result<String, ClientError> {
+    let session = match env::var("ADVENT_OF_CODE_SESSION") {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(path)
+            .map_err(|_| ClientError::SessionNotFound(path.to_path_buf()))?,
+    };
+
+    match session.trim() {
+        "" => Err(ClientError::SessionNotFound(path.to_path_buf())),
+        session => Ok(session.to_string()),
+    }
+}
+
+/// A client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client::with_user_agent(base_url, session, USER_AGENT)
+    }
+
+    pub fn with_user_agent(base_url: &str, session: &str, user_agent: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(user_agent)
+                .build(),
+        }
+    }
+
+    /// A client for `AOC_BASE_URL` (default: adventofcode.com) with the session of `read_session`,
+    /// sending `AOC_USER_AGENT` (default: `USER_AGENT`).
+    pub fn from_env() -> Result<Client, ClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.into());
+        let user_agent = env::var("AOC_USER_AGENT").unwrap_or_else(|_| USER_AGENT.into());
+        Ok(Client::with_user_agent(
+            &base_url,
+            &read_session(&session_path())?,
+            &user_agent,
+        ))
+    }
+
+    pub fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    /// The puzzle description as markdown, including part two once it is unlocked.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let html = self.get(&self.puzzle_url(year, day))?;
+        Ok(html_to_markdown(main_content(&html)))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("{}/input", self.puzzle_url(year, day)))
+    }
+
+    /// Submits `answer` for `part` and returns the server's response as markdown.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/answer", self.puzzle_url(year, day));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let html = read_response(response, &url)?;
+        Ok(html_to_markdown(main_content(&html)))
+    }
+
+    /// Downloads the input and the puzzle description into `src/inputs` and `src/puzzles`.
+    /// Returns the paths of both files.
+    pub fn download(&self, year: u16, day: u8) -> Result<(PathBuf, PathBuf), ClientError> {
+        let input_path = day_path("inputs", year, day, "txt");
+        let input = self.input(year, day)?;
+        let puzzle_path = self.download_puzzle(year, day)?;
+        write_file(&input_path, &input)?;
+
+        Ok((input_path, puzzle_path))
+    }
+
+    /// Downloads only the puzzle description into `src/puzzles` and returns its path.
+    pub fn download_puzzle(&self, year: u16, day: u8) -> Result<PathBuf, ClientError> {
+        let puzzle_path = day_path("puzzles", year, day, "md");
+        write_file(&puzzle_path, &self.puzzle(year, day)?)?;
+        Ok(puzzle_path)
+    }
+
+    fn get(&self, url: &str) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(response, url)
+    }
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), ClientError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(ClientError::IoError)?;
+    }
+    fs::write(path, contents).map_err(ClientError::IoError)
+}
+
+fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+    url: &str,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Network(e.to_string())),
+        // the server answers 400 for inputs without a session and 500 for invalid ones, but also
+        // 500 when it is overloaded, e.g. right after a puzzle unlocks. Only the former asks to log in.
+        Err(ureq::Error::Status(400 | 401, _)) => Err(ClientError::Unauthorized),
+        Err(ureq::Error::Status(500, response)) => match response.into_string() {
+            Ok(body) if body.to_lowercase().contains("log in") => Err(ClientError::Unauthorized),
+            _ => Err(ClientError::BadStatus(500, url.to_string())),
+        },
+        Err(ureq::Error::Status(404, _)) => Err(ClientError::NotFound(url.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::BadStatus(status, url.to_string())),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Network(e.to_string())),
+    }
+}
+
+/// The contents of the `<main>` element, or the whole page if there is none.
+fn main_content(html: &str) -> &str {
+    let start = html
+        .find("<main>")
+        .map_or(0, |index| index + "<main>".len());
+    let end = html[start..]
+        .find("</main>")
+        .map_or(html.len(), |index| start + index);
+    &html[start..end]
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Reads the value of `name` from the attributes of a tag, e.g. `href` of `a href="/2022"`.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+/// Converts the HTML of a puzzle page to the markdown aoc-cli used to write, e.g.
+/// `<em>` to `*em*`, `<code>` to `` `code` `` and `<pre>` to fenced code blocks.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut skip_until: Option<&str> = None;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    // starts a new block, e.g. a paragraph, after a blank line.
+    let block = |markdown: &mut String| {
+        let trimmed = markdown.trim_end_matches([' ', '\n']).len();
+        markdown.truncate(trimmed);
+        if !markdown.is_empty() {
+            markdown.push_str("\n\n");
+        }
+    };
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(0) => {
+                let end = rest.find('>').map_or(rest.len(), |index| index + 1);
+                let tag = &rest[1..end.saturating_sub(1).max(1)];
+                rest = &rest[end..];
+                ("", Some(tag))
+            }
+            Some(index) => {
+                let text = &rest[..index];
+                rest = &rest[index..];
+                (text, None)
+            }
+            None => {
+                let text = rest;
+                rest = "";
+                (text, None)
+            }
+        };
+
+        let Some(tag) = tag else {
+            if skip_until.is_some() {
+                continue;
+            }
+            let text = decode_entities(text);
+            if in_pre {
+                markdown.push_str(&text);
+            } else {
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                let starts_line = markdown.is_empty() || markdown.ends_with('\n');
+                if text.starts_with(char::is_whitespace) && !starts_line && !collapsed.is_empty() {
+                    markdown.push(' ');
+                }
+                markdown.push_str(&collapsed);
+                if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                    markdown.push(' ');
+                }
+            }
+            continue;
+        };
+
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .find(|part| !part.is_empty())
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let closing = tag.starts_with('/');
+
+        if let Some(until) = skip_until {
+            if closing && name == until {
+                skip_until = None;
+            }
+            continue;
+        }
+
+        match (name.as_str(), closing) {
+            ("script" | "style" | "form", false) => {
+                skip_until = Some(match name.as_str() {
+                    "script" => "script",
+                    "style" => "style",
+                    _ => "form",
+                })
+            }
+            ("h2", false) => {
+                block(&mut markdown);
+                markdown.push('\\');
+            }
+            ("h2", true) => markdown.push_str("\n----------\n\n"),
+            ("p" | "ul" | "article", _) => block(&mut markdown),
+            ("li", false) => {
+                let trimmed = markdown.trim_end_matches(' ').len();
+                markdown.truncate(trimmed);
+                if !markdown.is_empty() && !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("* ");
+            }
+            ("li", true) => markdown.truncate(markdown.trim_end().len()),
+            ("br", _) => markdown.push('\n'),
+            ("pre", false) => {
+                block(&mut markdown);
+                markdown.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("\n```\n\n");
+                in_pre = false;
+            }
+            ("code", _) if !in_pre => markdown.push('`'),
+            ("em", _) if !in_pre => markdown.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or("").to_string());
+                markdown.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    let mut markdown = markdown.trim_end().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown
+}
+
+/// A local stand-in for the Advent of Code server, used by the tests of the client and submissions.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Answers one request per entry of `responses` with its status and body, in order.
+    /// Returns the base url of the server and a receiver for the raw requests it received.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:")
+                    {
+                        content_length = length.trim().parse().unwrap_or(0);
+                    }
+                    request.push_str(&line);
+                }
+                let mut content = vec![0; content_length];
+                let _ = reader.read_exact(&mut content);
+                request.push_str(&String::from_utf8_lossy(&content));
+
+                let mut stream = reader.into_inner();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = sender.send(request);
+            }
+        });
+
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE_PAGE: &str = r#"<html><body><header>Advent of Code</header><main>
+<script>window.x = 1;</script>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>Santa's <a href="/2018/day/25">magical energy</a> needs <em>fifty stars</em>.</p>
+<pre><code>1000
+2000
+</code></pre>
+<ul>
+<li>The first Elf carries <code>1000</code>, a total of <code><em>6000</em></code> Calories.</li>
+<li>The second Elf &amp; friends.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>69289</code>.</p>
+<form method="post" action="1/answer"><input type="text" name="answer"/></form>
+</main></body></html>"#;
+
+    #[test]
+    fn test_html_to_markdown() {
+        assert_eq!(
+            html_to_markdown(main_content(PUZZLE_PAGE)),
+            "\\--- Day 1: Calorie Counting ---\n----------\n\n\
+             Santa's [magical energy](/2018/day/25) needs *fifty stars*.\n\n\
+             ```\n1000\n2000\n\n```\n\n\
+             * The first Elf carries `1000`, a total of `*6000*` Calories.\n\
+             * The second Elf & friends.\n\n\
+             Your puzzle answer was `69289`."
+        );
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let seconds = |year, day| {
+            unlock_time(year, day)
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+        assert_eq!(seconds(2022, 1), 1_669_870_800);
+        assert_eq!(seconds(2015, 25), 1_451_019_600);
+    }
+
+    #[test]
+    fn test_read_session() {
+        let path = env::temp_dir().join(format!("aoc-session-test-{}", std::process::id()));
+        if env::var("ADVENT_OF_CODE_SESSION").is_err() {
+            assert!(matches!(
+                read_session(&path),
+                Err(ClientError::SessionNotFound(_))
+            ));
+            fs::write(&path, "abc123\n").unwrap();
+            assert_eq!(read_session(&path).unwrap(), "abc123");
+            fs::remove_file(&path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_input_and_puzzle() {
+        let (base_url, requests) = stub::serve(vec![
+            (200, "1000\n2000\n".into()),
+            (200, PUZZLE_PAGE.into()),
+        ]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+
+        assert!(client
+            .puzzle(2022, 1)
+            .unwrap()
+            .starts_with("\\--- Day 1: Calorie Counting ---"));
+        assert!(requests
+            .recv()
+            .unwrap()
+            .starts_with("GET /2022/day/1 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = stub::serve(vec![(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>".into(),
+        )]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(
+            client.submit(2022, 7, 2, "24933642").unwrap(),
+            "That's the right answer! You are *one gold star* closer."
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=24933642"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let (base_url, _requests) = stub::serve(vec![
+            (404, "Not found".into()),
+            (400, "Please log in".into()),
+            (503, "Unavailable".into()),
+            (
+                500,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.".into(),
+            ),
+            (500, "Internal Server Error".into()),
+        ]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert!(matches!(
+            client.input(2022, 25),
+            Err(ClientError::NotFound(url)) if url.ends_with("/2022/day/25/input")
+        ));
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Unauthorized)
+        ));
+        assert!(matches!(
+            client.puzzle(2022, 1),
+            Err(ClientError::BadStatus(503, _))
+        ));
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Unauthorized)
+        ));
+        // an overloaded server is retried, see `is_transient`.
+        let error = client.input(2022, 1).unwrap_err();
+        assert!(matches!(error, ClientError::BadStatus(500, _)));
+        assert!(error.is_transient());
+
+        let client = Client::new("http://127.0.0.1:1", "abc123");
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Network(_))
+        ));
+    }
+}
@@ -0,0 +1,330 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ParseResult, PartResult, Phase};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const HISTORY_PATH: &str = "benchmarks.jsonl";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub day: u8,
+    /// The part, or `None` for the parse phase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phase: Option<Phase>,
+    pub elapsed_ns: u64,
+}
+
+/// The timings of one release run, stored as a line of `benchmarks.jsonl`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    pub year: u16,
+    pub commit: Option<String>,
+    pub dirty: bool,
+    pub name: Option<String>,
+    pub timestamp: u64,
+    pub timings: Vec<Timing>,
+}
+
+impl Run {
+    /// Collects the timings of all parse phases and solved parts, keyed by the current git commit
+    /// if there is one.
+    pub fn new(
+        year: u16,
+        parses: &[ParseResult],
+        results: &[PartResult],
+        name: Option<String>,
+    ) -> Run {
+        let parses = parses.iter().map(|parse| Timing {
+            day: parse.day,
+            part: None,
+            phase: Some(parse.phase),
+            elapsed_ns: parse.elapsed_ns,
+        });
+        let parts = results
+            .iter()
+            .filter(|result| result.solved)
+            .map(|result| Timing {
+                day: result.day,
+                part: Some(result.part),
+                phase: None,
+                elapsed_ns: result.elapsed_ns,
+            });
+
+        let (commit, dirty) = git_commit();
+        Run {
+            year,
+            commit,
+            dirty,
+            name,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            timings: parses.chain(parts).collect(),
+        }
+    }
+
+    fn timing(&self, day: u8, part: Option<u8>) -> Option<Duration> {
+        self.timings
+            .iter()
+            .find(|timing| timing.day == day && timing.part == part)
+            .map(|timing| Duration::from_nanos(timing.elapsed_ns))
+    }
+
+    /// A short description such as `abc1234 (dirty)` or `before-refactor`.
+    pub fn label(&self) -> String {
+        match (&self.name, &self.commit) {
+            (Some(name), _) => name.clone(),
+            (None, Some(commit)) => {
+                let commit = &commit[..commit.len().min(7)];
+                if self.dirty {
+                    format!("{commit} (dirty)")
+                } else {
+                    commit.into()
+                }
+            }
+            (None, None) => format!("run at {}", self.timestamp),
+        }
+    }
+}
+
+fn git_commit() -> (Option<String>, bool) {
+    let output = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match output(&["rev-parse", "HEAD"]) {
+        Some(commit) => {
+            let dirty = output(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+            (Some(commit), dirty)
+        }
+        None => (None, false),
+    }
+}
+
+/// Loads the runs of `year`, oldest first.
+pub fn load(path: &Path, year: u16) -> io::Result<Vec<Run>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .filter(|run: &io::Result<Run>| run.as_ref().map_or(true, |run| run.year == year))
+        .collect()
+}
+
+pub fn append(path: &Path, run: &Run) -> io::Result<()> {
+    let line =
+        serde_json::to_string(run).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Finds the latest run named `baseline`, or else the latest run of a commit starting with it.
+pub fn find_baseline<'a>(runs: &'a [Run], baseline: &str) -> Option<&'a Run> {
+    let is_commit = |run: &&Run| {
+        !baseline.is_empty() && run.commit.as_ref().is_some_and(|c| c.starts_with(baseline))
+    };
+
+    runs.iter()
+        .rev()
+        .find(|run| run.name.as_deref() == Some(baseline))
+        .or_else(|| runs.iter().rev().find(is_commit))
+}
+
+/// A part or parse phase that got slower than the allowed threshold.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    /// The part, or `None` for the parse phase.
+    pub part: Option<u8>,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    pub fn slowdown(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1_f64
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let phase = match self.part {
+            Some(part) => format!("part {part}"),
+            None => "parse".into(),
+        };
+        write!(
+            f,
+            "Day {:02}, {phase}: {:.2?} -> {:.2?} (+{:.1}%)",
+            self.day,
+            self.baseline,
+            self.current,
+            self.slowdown() * 100_f64
+        )
+    }
+}
+
+/// Lists every parse phase and part solved in both runs that is more than `threshold` (e.g. `0.1` for 10%)
+/// slower in `current` than in `baseline`.
+pub fn compare(baseline: &Run, current: &Run, threshold: f64) -> Vec<Regression> {
+    current
+        .timings
+        .iter()
+        .filter_map(|timing| {
+            let regression = Regression {
+                day: timing.day,
+                part: timing.part,
+                baseline: baseline.timing(timing.day, timing.part)?,
+                current: Duration::from_nanos(timing.elapsed_ns),
+            };
+            (!regression.baseline.is_zero() && regression.slowdown() > threshold)
+                .then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(name: Option<&str>, commit: Option<&str>, timings: &[(u8, u8, u64)]) -> Run {
+        Run {
+            year: 2022,
+            commit: commit.map(String::from),
+            dirty: false,
+            name: name.map(String::from),
+            timestamp: 0,
+            timings: timings
+                .iter()
+                .map(|&(day, part, elapsed_ns)| Timing {
+                    day,
+                    part: (part > 0).then_some(part),
+                    phase: (part == 0).then_some(Phase::Parse),
+                    elapsed_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        // part 0 is the parse phase.
+        let baseline = run(
+            None,
+            None,
+            &[(1, 0, 1000), (1, 1, 1000), (1, 2, 1000), (2, 1, 1000)],
+        );
+        let current = run(
+            None,
+            None,
+            &[(1, 0, 2000), (1, 1, 1050), (1, 2, 1200), (3, 1, 5000)],
+        );
+
+        let regressions = compare(&baseline, &current, 0.1);
+        assert_eq!(
+            regressions,
+            vec![
+                Regression {
+                    day: 1,
+                    part: None,
+                    baseline: Duration::from_nanos(1000),
+                    current: Duration::from_nanos(2000),
+                },
+                Regression {
+                    day: 1,
+                    part: Some(2),
+                    baseline: Duration::from_nanos(1000),
+                    current: Duration::from_nanos(1200),
+                }
+            ]
+        );
+        assert_eq!(
+            regressions[0].to_string(),
+            "Day 01, parse: 1.00µs -> 2.00µs (+100.0%)"
+        );
+        assert_eq!(compare(&baseline, &current, 0.01).len(), 3);
+    }
+
+    #[test]
+    fn test_find_baseline() {
+        let runs = vec![
+            run(Some("before"), Some("aaaa111"), &[]),
+            run(None, Some("bbbb222"), &[]),
+            run(Some("before"), Some("cccc333"), &[]),
+        ];
+
+        assert_eq!(
+            find_baseline(&runs, "before").and_then(|r| r.commit.as_deref()),
+            Some("cccc333")
+        );
+        assert_eq!(
+            find_baseline(&runs, "bbbb").and_then(|r| r.commit.as_deref()),
+            Some("bbbb222")
+        );
+        assert_eq!(find_baseline(&runs, "dddd"), None);
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let path = std::env::temp_dir().join(format!(
+            "aoc-benchmark-history-{}.jsonl",
+            std::process::id()
+        ));
+        let first = run(None, Some("aaaa111"), &[(1, 0, 300), (1, 1, 1000)]);
+        let second = run(Some("after"), None, &[(1, 1, 900)]);
+
+        let other_year = Run {
+            year: 2015,
+            ..first.clone()
+        };
+
+        append(&path, &first).unwrap();
+        append(&path, &other_year).unwrap();
+        append(&path, &second).unwrap();
+        // runs from before parse phases were recorded.
+        fs::write(
+            &path,
+            fs::read_to_string(&path).unwrap()
+                + r#"{"year":2022,"commit":null,"dirty":false,"name":null,"timestamp":0,"timings":[{"day":1,"part":1,"elapsed_ns":5}]}"#
+                + "\n",
+        )
+        .unwrap();
+        assert_eq!(load(&path, 2022).unwrap()[..2], [first, second]);
+        assert_eq!(
+            load(&path, 2022).unwrap()[2].timings,
+            vec![Timing {
+                day: 1,
+                part: Some(1),
+                phase: None,
+                elapsed_ns: 5,
+            }]
+        );
+        assert_eq!(load(&path, 2015).unwrap(), vec![other_year]);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -19,8 +19,10 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(rev(sorted(elves.map(sum_elf))).take(3).sum())
 }
 
+advent_of_code::solution!(2022, 1, "Calorie Counting");
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -32,18 +34,18 @@ mod tests {
     #[test]
     fn test_sum_elf() {
         let input = "1\n2\n3";
-        assert_eq!(sum_elf(&input), 6);
+        assert_eq!(sum_elf(input), 6);
     }
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), Some(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), Some(45000));
     }
 }
@@ -25,12 +25,12 @@ pub fn part_two(input: &str) -> Option<u32> {
 
 pub fn play_round(input: &str) -> u32 {
     let my_move = to_rock_paper_scissors(&input.chars().nth(2).unwrap());
-    let their_move = to_rock_paper_scissors(&input.chars().nth(0).unwrap());
+    let their_move = to_rock_paper_scissors(&input.chars().next().unwrap());
     rock_paper_scissors(&my_move, &their_move) + moves_to_score(&my_move)
 }
 
 pub fn play_round_part_two(input: &str) -> u32 {
-    let their_move = to_rock_paper_scissors(&input.chars().nth(0).unwrap());
+    let their_move = to_rock_paper_scissors(&input.chars().next().unwrap());
     let which_outcome = to_outcome(&input.chars().nth(2).unwrap());
 
     let possible_moves = move_set(&their_move);
@@ -126,8 +126,10 @@ pub fn rock_paper_scissors(us: &Moves, them: &Moves) -> u32 {
     }
 }
 
+advent_of_code::solution!(2022, 2, "Rock Paper Scissors");
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -168,13 +170,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), Some(12));
     }
 }
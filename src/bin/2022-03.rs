@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
-const UPPER_A_ORD: u8 = 'A' as u8;
-const LOWER_A_ORD: u8 = 'a' as u8;
+const UPPER_A_ORD: u8 = b'A';
+const LOWER_A_ORD: u8 = b'a';
 
 pub fn part_one(input: &str) -> Option<usize> {
     let rucksacks = input.trim_end().split('\n');
@@ -17,7 +17,7 @@ pub fn find_in_both<'a>(left: &'a str, right: &'a str) -> char {
     let left_set: HashSet<char> = left.chars().collect();
     let right_set: HashSet<char> = right.chars().collect();
 
-    left_set.intersection(&right_set).last().unwrap().clone()
+    *left_set.intersection(&right_set).last().unwrap()
 }
 
 pub fn to_priority(c: char) -> usize {
@@ -31,7 +31,7 @@ pub fn to_priority(c: char) -> usize {
 pub fn part_two(input: &str) -> Option<usize> {
     let mut rucksacks = input.trim_end().split('\n').peekable();
     let mut result = 0;
-    while rucksacks.peek() != None {
+    while rucksacks.peek().is_some() {
         let one = rucksacks.next().unwrap();
         let two = rucksacks.next().unwrap();
         let three = rucksacks.next().unwrap();
@@ -48,11 +48,13 @@ pub fn find_in_three<'a>(one: &'a str, two: &'a str, three: &'a str) -> char {
     let three_set: HashSet<char> = three.chars().collect();
 
     let one_two_set: HashSet<char> = one_set.intersection(&two_set).copied().collect();
-    one_two_set.intersection(&three_set).last().unwrap().clone()
+    *one_two_set.intersection(&three_set).last().unwrap()
 }
 
+advent_of_code::solution!(2022, 3, "Rucksack Reorganization");
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -112,13 +114,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), Some(70));
     }
 }
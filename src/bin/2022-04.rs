@@ -20,7 +20,7 @@ pub fn range_contains(first: &str, second: &str) -> bool {
 /// Parses a range of the form i-j into two boundaries (i, j)
 pub fn parse_range(range: &str) -> (usize, usize) {
     let boundaries = range.split('-');
-    let lower: usize = boundaries.clone().nth(0).unwrap().parse().unwrap();
+    let lower: usize = boundaries.clone().next().unwrap().parse().unwrap();
     let upper: usize = boundaries.clone().nth(1).unwrap().parse().unwrap();
     (lower, upper)
 }
@@ -28,7 +28,7 @@ pub fn parse_range(range: &str) -> (usize, usize) {
 /// Parses a pair of the form X,Y into two string slices (X, Y)
 pub fn parse_pairs(pairs: &str) -> (&str, &str) {
     let split = pairs.split(',');
-    (split.clone().nth(0).unwrap(), split.clone().nth(1).unwrap())
+    (split.clone().next().unwrap(), split.clone().nth(1).unwrap())
 }
 
 /// How many pairs overlap at all?
@@ -57,8 +57,10 @@ pub fn range_overlaps(first: &str, second: &str) -> bool {
     }
 }
 
+advent_of_code::solution!(2022, 4, "Camp Cleanup");
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -102,13 +104,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), Some(4));
     }
 }
@@ -1,22 +1,41 @@
 use itertools::Itertools;
 use regex::Regex;
 
-/// This was truly awful.
-pub fn part_one(input: &str) -> Option<String> {
+pub struct Procedure {
+    pub stacks: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>,
+}
+
+pub fn parse(input: &str) -> Procedure {
     let mut parts = input.trim_end().split("\n\n");
 
-    let mut stacks = read_map(parts.next().unwrap());
-    parts
+    let stacks = read_map(parts.next().unwrap());
+    let re = Regex::new(r"^move (?<qty>\d+) from (?<from>\d+) to (?<to>\d+)").expect("Oops");
+    let instructions = parts
         .next()
         .unwrap()
         .split('\n')
+        .map(|instruction| parse_instruction_with(&re, instruction))
+        .collect();
+
+    Procedure {
+        stacks,
+        instructions,
+    }
+}
+
+/// This was truly awful.
+pub fn part_one(procedure: &Procedure) -> Option<String> {
+    let mut stacks = procedure.stacks.clone();
+    procedure
+        .instructions
+        .iter()
         .for_each(|instruction| apply_instruction(instruction, &mut stacks));
 
     Some(stacks.iter().map(|s| s.last().unwrap()).collect::<String>())
 }
 
-pub fn apply_instruction(instruction: &str, stacks: &mut Vec<Vec<char>>) {
-    let instruction = parse_instruction(instruction);
+pub fn apply_instruction(instruction: &Instruction, stacks: &mut [Vec<char>]) {
     for _ in 0..instruction.qty {
         let popped = stacks.get_mut(instruction.from).unwrap().pop().unwrap();
         stacks.get_mut(instruction.to).unwrap().push(popped);
@@ -35,7 +54,7 @@ pub fn read_map(map: &str) -> Vec<Vec<char>> {
             .collect_vec()
     });
 
-    let length = tmp.clone().last().unwrap().iter().count();
+    let length = tmp.clone().last().unwrap().len();
     tmp.fold(vec![vec![]; length], |mut s: Vec<Vec<char>>, line| {
         s.iter_mut().zip(line).for_each(|(stack, ele)| {
             if let Some(c) = ele {
@@ -55,6 +74,10 @@ pub struct Instruction {
 
 pub fn parse_instruction(instruction: &str) -> Instruction {
     let re = Regex::new(r"^move (?<qty>\d+) from (?<from>\d+) to (?<to>\d+)").expect("Oops");
+    parse_instruction_with(&re, instruction)
+}
+
+fn parse_instruction_with(re: &Regex, instruction: &str) -> Instruction {
     let caps = re
         .captures(instruction)
         .expect("Could not parse instruction");
@@ -71,21 +94,17 @@ pub fn parse_instruction(instruction: &str) -> Instruction {
     }
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut parts = input.trim_end().split("\n\n");
-
-    let mut stacks = read_map(parts.next().unwrap());
-    parts
-        .next()
-        .unwrap()
-        .split('\n')
+pub fn part_two(procedure: &Procedure) -> Option<String> {
+    let mut stacks = procedure.stacks.clone();
+    procedure
+        .instructions
+        .iter()
         .for_each(|instruction| apply_improved_instruction(instruction, &mut stacks));
 
     Some(stacks.iter().map(|s| s.last().unwrap()).collect::<String>())
 }
 
-pub fn apply_improved_instruction(instruction: &str, stacks: &mut Vec<Vec<char>>) {
-    let instruction = parse_instruction(instruction);
+pub fn apply_improved_instruction(instruction: &Instruction, stacks: &mut [Vec<char>]) {
     let mut holding = vec![];
 
     for _ in 0..instruction.qty {
@@ -93,13 +112,16 @@ pub fn apply_improved_instruction(instruction: &str, stacks: &mut Vec<Vec<char>>
     }
 
     let to = stacks.get_mut(instruction.to).unwrap();
-    holding.iter().rev().for_each(|e| to.push(e.clone()));
+    holding.iter().rev().for_each(|e| to.push(*e));
 }
 
+advent_of_code::solution!(2022, 5, "Supply Stacks", parse);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_input(YEAR, DAY);
+    let procedure = advent_of_code::parse!(parse, input);
+    advent_of_code::solve!(1, part_one, &procedure);
+    advent_of_code::solve!(2, part_two, &procedure);
 }
 
 #[cfg(test)]
@@ -120,13 +142,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some(String::from("CMZ")));
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+        assert_eq!(part_one(&parse(&input)), Some(String::from("CMZ")));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some(String::from("MCD")));
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+        assert_eq!(part_two(&parse(&input)), Some(String::from("MCD")));
     }
 }
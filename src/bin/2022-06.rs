@@ -32,8 +32,10 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(offset)
 }
 
+advent_of_code::solution!(2022, 6, "Tuning Trouble");
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -44,13 +46,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), Some(19));
     }
 }
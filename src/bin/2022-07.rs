@@ -2,12 +2,27 @@ use std::{cell::RefCell, collections::HashMap, iter::Peekable, path::PathBuf, rc
 
 use regex::Regex;
 
-pub fn part_one(input: &str) -> Option<u32> {
+/// The size of the root directory and of every directory below it.
+pub struct DirectorySizes {
+    pub root: u32,
+    pub children: Vec<u32>,
+}
+
+pub fn parse(input: &str) -> DirectorySizes {
     let all_dirs = execute(input.trim_end());
 
     let mut root = all_dirs.get(&PathBuf::from("/")).unwrap().borrow_mut();
-    let mut results = root.resolve(&all_dirs);
-    results.push(root.size);
+    let children = root.resolve(&all_dirs);
+
+    DirectorySizes {
+        root: root.size,
+        children,
+    }
+}
+
+pub fn part_one(sizes: &DirectorySizes) -> Option<u32> {
+    let mut results = sizes.children.clone();
+    results.push(sizes.root);
 
     Some(results.iter().filter(|s| **s < 100_000).sum::<u32>())
 }
@@ -38,7 +53,7 @@ pub fn execute_ls(
     execution: &mut Peekable<Split<'_, char>>,
     cwd: PathBuf,
 ) {
-    if let None = all_dirs.get(&cwd) {
+    if all_dirs.get(&cwd).is_none() {
         all_dirs.insert(
             cwd.clone(),
             Rc::new(RefCell::new(Directory::new(cwd.clone()))),
@@ -49,7 +64,7 @@ pub fn execute_ls(
 
     while let Some(next_line) = execution.peek() {
         // Bail if we've reached the end of the ls output
-        if is_command(*next_line) {
+        if is_command(next_line) {
             break;
         }
         let line = execution.next().unwrap();
@@ -70,16 +85,12 @@ pub fn execute_ls(
     }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let all_dirs = execute(input.trim_end());
-
-    let mut root = all_dirs.get(&PathBuf::from("/")).unwrap().borrow_mut();
-    let directory_sizes = root.resolve(&all_dirs);
-
-    let space_available = 70_000_000 - root.size;
+pub fn part_two(sizes: &DirectorySizes) -> Option<u32> {
+    let space_available = 70_000_000 - sizes.root;
 
     Some(
-        *directory_sizes
+        *sizes
+            .children
             .iter()
             .filter(|s| space_available + **s > 30_000_000)
             .min()
@@ -127,20 +138,18 @@ impl Directory {
     }
 }
 
-pub fn parse_command(line: &str) -> Command {
+pub fn parse_command(line: &str) -> Command<'_> {
     let r = Regex::new(r"\$ ((?<ls>ls)|(?<cd>cd (?<arg>.+)))$").expect("Oops!");
     let caps = r.captures(line).expect("Oops!");
 
     match caps {
-        caps if matches!(caps.name("ls"), Some(_)) => Command::LS,
-        caps if matches!(caps.name("cd"), Some(_)) => {
-            Command::CD(caps.name("arg").unwrap().as_str())
-        }
+        caps if caps.name("ls").is_some() => Command::LS,
+        caps if caps.name("cd").is_some() => Command::CD(caps.name("arg").unwrap().as_str()),
         _ => panic!("Oops!"),
     }
 }
 
-pub fn execute_cd<'a>(cmd: &'a str, mut pwd: PathBuf) -> PathBuf {
+pub fn execute_cd(cmd: &str, mut pwd: PathBuf) -> PathBuf {
     match cmd {
         "/" => PathBuf::from("/"),
         ".." => {
@@ -155,7 +164,7 @@ pub fn execute_cd<'a>(cmd: &'a str, mut pwd: PathBuf) -> PathBuf {
 }
 
 pub fn is_command(line: &str) -> bool {
-    line.chars().next().unwrap() == '$'
+    line.starts_with('$')
 }
 
 pub enum LsOutput<'a> {
@@ -168,10 +177,8 @@ pub fn parse_ls_output<'a>(line: &'a str) -> LsOutput<'a> {
     let caps = r.captures(line).expect("Oops!");
 
     match caps {
-        caps if matches!(caps.name("dir"), Some(_)) => {
-            LsOutput::Dir(caps.name("dir").unwrap().as_str())
-        }
-        caps if matches!(caps.name("file"), Some(_)) => LsOutput::File(
+        caps if caps.name("dir").is_some() => LsOutput::Dir(caps.name("dir").unwrap().as_str()),
+        caps if caps.name("file").is_some() => LsOutput::File(
             caps.name("file").unwrap().as_str(),
             caps.name("size").unwrap().as_str().parse().unwrap(),
         ),
@@ -179,10 +186,13 @@ pub fn parse_ls_output<'a>(line: &'a str) -> LsOutput<'a> {
     }
 }
 
+advent_of_code::solution!(2022, 7, "No Space Left On Device", parse);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_input(YEAR, DAY);
+    let sizes = advent_of_code::parse!(parse, input);
+    advent_of_code::solve!(1, part_one, &sizes);
+    advent_of_code::solve!(2, part_two, &sizes);
 }
 
 #[cfg(test)]
@@ -234,13 +244,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95_437));
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+        assert_eq!(part_one(&parse(&input)), Some(95_437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+        assert_eq!(part_two(&parse(&input)), Some(24933642));
     }
 }
@@ -1,13 +1,15 @@
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(_input: &str) -> Option<u32> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
+advent_of_code::solution!(2022, 8, "Treetop Tree House");
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -19,13 +21,13 @@ mod tests {
     #[test]
     #[ignore]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_one(&input), Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
         assert_eq!(part_two(&input), None);
     }
 }
@@ -2,22 +2,120 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{
+    aoc_client::{self, Client, ClientError},
+    puzzle,
+};
+use std::{
+    env,
+    io::{self, Write},
+    path::Path,
+    process::{self, Command},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often a download is tried before giving up.
+const ATTEMPTS: u32 = 8;
+/// The wait before the first retry, doubled for every further retry.
+const BACKOFF: Duration = Duration::from_secs(1);
 
 struct Args {
+    year: u16,
     day: u8,
-    year: Option<u16>,
+    wait: bool,
+    puzzle_only: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        wait: args.contains("--wait"),
+        puzzle_only: args.contains("--puzzle-only"),
+        year: advent_of_code::parse_year(&mut args)?,
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
+/// Formats a duration as e.g. `2h 03m 15s`.
+fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    format!(
+        "{}h {:02}m {:02}s",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Sleeps until `unlock`, counting down on a single line.
+fn wait_for_unlock(day: u8, unlock: SystemTime) {
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        print!(
+            "\r⏳ Day {day} unlocks in {}  ",
+            format_countdown(remaining)
+        );
+        let _ = io::stdout().flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+    println!("\r🔓 Day {day} is unlocked.                    ");
+}
+
+/// Calls `func` until it succeeds or fails with an error that is not transient, waiting
+/// `backoff`, then twice as long and so on between attempts.
+fn with_retries<T>(
+    attempts: u32,
+    backoff: Duration,
+    mut func: impl FnMut() -> Result<T, ClientError>,
+) -> Result<T, ClientError> {
+    let mut delay = backoff;
+    let mut attempt = 1;
+    loop {
+        match func() {
+            Err(e) if e.is_transient() && attempt < attempts => {
+                eprintln!("{e} Retrying in {delay:.0?}...");
+                thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Scaffolds the day through `cargo scaffold` unless its module exists already.
+fn scaffold(year: u16, day: u8) {
+    if Path::new(&format!("src/bin/{year}-{day:02}.rs")).exists() {
+        return;
+    }
+
+    println!("---");
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .args(["scaffold", &day.to_string(), "--year", &year.to_string()])
+        .status();
+
+    if !status.is_ok_and(|status| status.success()) {
+        eprintln!("Failed to scaffold day {day}.");
+        process::exit(1);
+    }
+}
+
+/// Downloads the puzzle description again and prints what is new in it, e.g. part two.
+fn refresh_puzzle(client: &Client, year: u16, day: u8, attempts: u32) {
+    match with_retries(attempts, BACKOFF, || puzzle::refresh(client, year, day)) {
+        Ok(Some(section)) => {
+            println!("🎄 Updated the puzzle of day {day}, new since the last download:");
+            println!("---");
+            puzzle::print(&section);
+        }
+        Ok(None) => println!("🎄 The puzzle of day {day} has not changed."),
+        Err(e) => {
+            eprintln!("Failed to download the puzzle of day {day}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
@@ -27,20 +125,84 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    if args.wait {
+        wait_for_unlock(args.day, aoc_client::unlock_time(args.year, args.day));
     }
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
+    let attempts = if args.wait { ATTEMPTS } else { 1 };
+
+    if args.puzzle_only {
+        refresh_puzzle(&client, args.year, args.day, attempts);
+        return;
+    }
+
+    match with_retries(attempts, BACKOFF, || client.download(args.year, args.day)) {
+        Ok((input_path, puzzle_path)) => {
+            println!("---");
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
+            println!(
+                "🎄 Successfully wrote puzzle to \"{}\".",
+                puzzle_path.display()
+            );
         }
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("Failed to download day {}: {e}", args.day);
             process::exit(1);
         }
     }
+
+    if args.wait {
+        scaffold(args.year, args.day);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(7395)), "2h 03m 15s");
+        assert_eq!(format_countdown(Duration::from_millis(900)), "0h 00m 00s");
+    }
+
+    #[test]
+    fn test_with_retries() {
+        let mut calls = 0;
+        let result = with_retries(3, Duration::ZERO, || {
+            calls += 1;
+            match calls {
+                1 => Err(ClientError::NotFound("/2022/day/1".into())),
+                _ => Ok(calls),
+            }
+        });
+        assert_eq!(result.ok(), Some(2));
+
+        let mut calls = 0;
+        let result: Result<(), _> = with_retries(3, Duration::ZERO, || {
+            calls += 1;
+            Err(ClientError::Network("connection refused".into()))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result: Result<(), _> = with_retries(3, Duration::ZERO, || {
+            calls += 1;
+            Err(ClientError::Unauthorized)
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
 }
@@ -2,22 +2,44 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{aoc_client::Client, input::day_path, puzzle};
+use std::{fs, process};
 
 struct Args {
+    year: u16,
     day: u8,
-    year: Option<u16>,
+    part: Option<u8>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        year: advent_of_code::parse_year(&mut args)?,
+        part: args.opt_value_from_fn("--part", advent_of_code::parse_part)?,
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
+/// Reads the puzzle from `src/puzzles`, or fetches it if it was not downloaded yet.
+fn load_puzzle(year: u16, day: u8) -> String {
+    let path = day_path("puzzles", year, day, "md");
+    if let Ok(puzzle) = fs::read_to_string(&path) {
+        return puzzle;
+    }
+
+    let fetched = Client::from_env().and_then(|client| client.puzzle(year, day));
+    match fetched {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!(
+                "\"{}\" does not exist and fetching it failed: {e}",
+                path.display()
+            );
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
     let args = match parse_args() {
         Ok(args) => args,
@@ -27,20 +49,19 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+    let puzzle = load_puzzle(args.year, args.day);
+    let puzzle = match (args.part, puzzle::split_parts(&puzzle)) {
+        (Some(1), (part_one, _)) => part_one,
+        (Some(2), (_, Some(part_two))) => part_two,
+        (Some(2), (_, None)) => {
+            eprintln!(
+                "Part two of day {} is not unlocked yet. Once part one is solved, run `cargo download {} --puzzle-only` to fetch it.",
+                args.day, args.day
+            );
             process::exit(1);
         }
-    }
+        _ => &puzzle,
+    };
+
+    puzzle::print(puzzle);
 }
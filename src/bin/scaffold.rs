@@ -2,22 +2,29 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{
+    input::day_path,
+    puzzle::{self, Example},
+};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, IsTerminal, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"pub fn part_one(_input: &str) -> Option<u32> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(_input: &str) -> Option<u32> {
     None
 }
 
+advent_of_code::solution!(YEAR, DAY);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
+    let input = &advent_of_code::read_input(YEAR, DAY);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -25,24 +32,207 @@ fn main() {
 #[cfg(test)]
 mod tests {
     use super::*;
+TESTS}
+"###;
 
+const DEFAULT_TESTS: &str = r###"
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+        assert_eq!(part_one(&input), PART_ONE);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+        assert_eq!(part_two(&input), PART_TWO);
+    }
+"###;
+
+const EXAMPLE_TEST: &str = r###"
+    #[test]
+    fn test_example_IDENT() {
+        let input = advent_of_code::read_example(YEAR, DAY, "NAME");
+        assert_eq!(part_one(&input), PART_ONE);
+        assert_eq!(part_two(&input), PART_TWO);
     }
-}
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+/// An example file the generated tests read, with the answers the puzzle gives for it.
+struct ExampleTest {
+    name: String,
+    part_one: Option<String>,
+    part_two: Option<String>,
+}
+
+/// The expected value of a generated assertion, e.g. `Some(24000));`. Answers that do not fit
+/// the template's `Option<u32>`, e.g. text or larger numbers, are left as a comment.
+fn expected(answer: &Option<String>) -> String {
+    match answer {
+        Some(answer) if answer.parse::<u32>().is_ok() => format!("Some({answer}));"),
+        Some(answer) => format!("None); // expected: {answer:?}"),
+        None => "None);".into(),
+    }
+}
+
+/// One test per example file, or tests against `src/examples/<day>.txt` if that is the only
+/// one or there are none yet.
+fn render_tests(year: u16, day: u8, examples: &[ExampleTest]) -> String {
+    let tests = match examples {
+        [] => DEFAULT_TESTS
+            .replace("PART_ONE);", &expected(&None))
+            .replace("PART_TWO);", &expected(&None)),
+        [example] if example.name.is_empty() => DEFAULT_TESTS
+            .replace("PART_ONE);", &expected(&example.part_one))
+            .replace("PART_TWO);", &expected(&example.part_two)),
+        _ => examples.iter().map(render_example_test).collect(),
+    };
+
+    tests
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string())
+}
+
+fn render_example_test(example: &ExampleTest) -> String {
+    let ident: String = example
+        .name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '_',
+        })
+        .collect();
+    EXAMPLE_TEST
+        .replace(
+            "_IDENT",
+            &if ident.is_empty() {
+                ident
+            } else {
+                format!("_{ident}")
+            },
+        )
+        .replace("NAME", &example.name)
+        .replace("PART_ONE);", &expected(&example.part_one))
+        .replace("PART_TWO);", &expected(&example.part_two))
+}
+
+/// Parses a pick like `1` or `1,3` of `count` examples. An empty pick is the first example.
+fn parse_pick(pick: &str, count: usize) -> Option<Vec<usize>> {
+    if pick.trim().is_empty() {
+        return Some(vec![0]);
+    }
+
+    let mut picked = vec![];
+    for number in pick.split(',') {
+        let number: usize = number.trim().parse().ok()?;
+        if !(1..=count).contains(&number) {
+            return None;
+        }
+        picked.push(number - 1);
+    }
+    picked.sort();
+    picked.dedup();
+    Some(picked)
+}
+
+/// Asks which of the examples found in the puzzle description to use. Without a terminal to
+/// ask on, the first one is used.
+fn pick_examples(mut examples: Vec<Example>) -> Vec<(usize, Example)> {
+    if examples.len() < 2 || !io::stdin().is_terminal() {
+        examples.truncate(1);
+        return examples.into_iter().enumerate().collect();
+    }
+
+    println!("Found {} possible examples:", examples.len());
+    for (index, example) in examples.iter().enumerate() {
+        let answers: Vec<String> = [
+            ("part one", &example.part_one),
+            ("part two", &example.part_two),
+        ]
+        .into_iter()
+        .filter_map(|(part, answer)| Some(format!("{part}: {}", answer.as_ref()?)))
+        .collect();
+        println!("---");
+        println!("[{}] {}", index + 1, answers.join(", "));
+        let lines: Vec<&str> = example.input.lines().collect();
+        for line in lines.iter().take(5) {
+            println!("    {line}");
+        }
+        if lines.len() > 5 {
+            println!("    ... ({} more lines)", lines.len() - 5);
+        }
+    }
+    println!("---");
+
+    loop {
+        print!("Which examples should be used? (e.g. `1` or `1,3`, default `1`): ");
+        let _ = io::stdout().flush();
+        let mut pick = String::new();
+        if io::stdin().read_line(&mut pick).unwrap_or(0) == 0 {
+            examples.truncate(1);
+            return examples.into_iter().enumerate().collect();
+        }
+        match parse_pick(&pick, examples.len()) {
+            Some(picked) => {
+                return examples
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(index, _)| picked.contains(index))
+                    .collect()
+            }
+            None => eprintln!("Pick between 1 and {}.", examples.len()),
+        }
+    }
+}
+
+/// Writes the picked examples of `src/puzzles/<year>/<day>.md` to `src/examples`: a single one
+/// to `<day>.txt`, several to `<day>-<number>.txt`. Returns nothing if the puzzle is not
+/// downloaded or contains no examples.
+fn extract_examples(year: u16, day: u8) -> Vec<ExampleTest> {
+    let Ok(markdown) = fs::read_to_string(day_path("puzzles", year, day, "md")) else {
+        return vec![];
+    };
+
+    let picked = pick_examples(puzzle::examples(&markdown));
+    let single = picked.len() == 1;
+    let mut tests = vec![];
+
+    for (index, example) in picked {
+        let name = if single {
+            String::new()
+        } else {
+            (index + 1).to_string()
+        };
+        let path = match single {
+            true => day_path("examples", year, day, "txt"),
+            false => day_path("examples", year, day, "txt")
+                .with_file_name(format!("{day:02}-{name}.txt")),
+        };
+        let path = path.display().to_string();
+        if let Err(e) =
+            create_file(&path).and_then(|mut file| file.write_all(example.input.as_bytes()))
+        {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+        println!("Created example file \"{path}\" from the puzzle description");
+
+        tests.push(ExampleTest {
+            name,
+            part_one: example.part_one,
+            part_two: example.part_two,
+        });
+    }
+
+    tests
+}
+
+fn parse_args() -> Result<(u16, u8), pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok((
+        advent_of_code::parse_year(&mut args)?,
+        args.free_from_str()?,
+    ))
 }
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
@@ -50,12 +240,25 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let (year, day) = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingOption(_)) => {
+            eprintln!(
+                "Need to specify a year, either with `--year` or as `AOC_YEAR` in `.cargo/config`."
+            );
+            process::exit(1);
+        }
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
@@ -64,9 +267,34 @@ fn main() {
 
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let input_path = day_path("inputs", year, day, "txt").display().to_string();
+    let example_path = day_path("examples", year, day, "txt").display().to_string();
+    let module_path = format!("src/bin/{year}-{day_padded}.rs");
+
+    // checked before examples are written, the module itself is only created once it is rendered.
+    if Path::new(&module_path).exists() {
+        eprintln!("Failed to create module file: \"{module_path}\" already exists");
+        process::exit(1);
+    }
+
+    let names = advent_of_code::input::example_names(year, day).unwrap_or_default();
+    let extracted = names.is_empty();
+    let examples = match extracted {
+        true => extract_examples(year, day),
+        false => names
+            .into_iter()
+            .map(|name| ExampleTest {
+                name,
+                part_one: None,
+                part_two: None,
+            })
+            .collect(),
+    };
+
+    let module = MODULE_TEMPLATE
+        .replace("YEAR", &year.to_string())
+        .replace("DAY", &day.to_string())
+        .replace("TESTS", &render_tests(year, day, &examples));
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,7 +304,7 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -96,19 +324,88 @@ fn main() {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    if examples.is_empty() {
+        match create_file(&example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {e}");
+                process::exit(1);
+            }
         }
+    } else if !extracted {
+        println!("Generated tests for {} example files", examples.len());
     }
 
     println!("---");
+    let year_arg = match advent_of_code::default_year() {
+        Some(default) if default == year => String::new(),
+        _ => format!(" --year {year}"),
+    };
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {}{}` to run your solution.",
+        &day_padded, year_arg
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pick() {
+        assert_eq!(parse_pick("\n", 3), Some(vec![0]));
+        assert_eq!(parse_pick("3, 1,3\n", 3), Some(vec![0, 2]));
+        assert_eq!(parse_pick("4", 3), None);
+        assert_eq!(parse_pick("a", 3), None);
+    }
+
+    #[test]
+    fn test_render_tests() {
+        let tests = render_tests(
+            2022,
+            5,
+            &[ExampleTest {
+                name: String::new(),
+                part_one: Some("CMZ".into()),
+                part_two: Some("12".into()),
+            }],
+        );
+        assert!(tests.contains("assert_eq!(part_one(&input), None); // expected: \"CMZ\"\n"));
+        assert!(tests.contains("assert_eq!(part_two(&input), Some(12));\n"));
+        assert!(tests.contains("read_file(\"examples\", 2022, 5)"));
+
+        let tests = render_tests(
+            2022,
+            5,
+            &[ExampleTest {
+                name: String::new(),
+                part_one: Some("4294967296".into()),
+                part_two: None,
+            }],
+        );
+        assert!(tests.contains("assert_eq!(part_one(&input), None); // expected: \"4294967296\"\n"));
+
+        let tests = render_tests(
+            2022,
+            5,
+            &[
+                ExampleTest {
+                    name: "1".into(),
+                    part_one: None,
+                    part_two: None,
+                },
+                ExampleTest {
+                    name: "Large Grid".into(),
+                    part_one: Some("7".into()),
+                    part_two: None,
+                },
+            ],
+        );
+        assert!(tests.contains("fn test_example_1()"));
+        assert!(tests.contains("fn test_example_large_grid()"));
+        assert!(tests.contains("read_example(2022, 5, \"Large Grid\")"));
+        assert!(tests.contains("assert_eq!(part_one(&input), Some(7));"));
+    }
+}
@@ -0,0 +1,113 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    ffi::OsString,
+    process::{self, Command},
+};
+
+struct Args {
+    year: u16,
+    day: u8,
+    /// Flags for cargo, e.g. `--release` or `--features allocations`.
+    cargo_args: Vec<OsString>,
+    /// Everything after `--` and `--part`, passed on to the day's binary.
+    day_args: Vec<OsString>,
+}
+
+fn parse_args(mut args: Vec<OsString>) -> Result<Args, pico_args::Error> {
+    let mut day_args: Vec<OsString> = match args.iter().position(|arg| arg == "--") {
+        Some(index) => args.split_off(index).into_iter().skip(1).collect(),
+        None => vec![],
+    };
+
+    let mut args = pico_args::Arguments::from_vec(args);
+    let checked = args.contains("--checked");
+    if let Some(part) = args.opt_value_from_str::<_, OsString>("--part")? {
+        day_args.splice(0..0, ["--part".into(), part]);
+    }
+    let year = advent_of_code::parse_year(&mut args)?;
+    let day = args.free_from_str()?;
+    let mut cargo_args = args.finish();
+
+    // `--checked` swaps the release profile for the `checked` profile, which adds overflow checks.
+    if checked {
+        cargo_args.retain(|arg| arg != "--release" && arg != "-r");
+        cargo_args.extend(["--profile".into(), "checked".into()]);
+    }
+
+    Ok(Args {
+        year,
+        day,
+        cargo_args,
+        day_args,
+    })
+}
+
+/// Runs the binary of a day, `src/bin/<year>-<day>.rs`.
+/// example: `cargo solve 01 --year 2015 --release -- --bench`
+/// `cargo solve 01 --release --checked` keeps optimizations but panics on integer overflow.
+/// `cargo solve 01 --part 1` only runs part one.
+fn main() {
+    let args = match parse_args(env::args_os().skip(1).collect()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .arg("run")
+        .args(&args.cargo_args)
+        .args(["--bin", &format!("{}-{:02}", args.year, args.day), "--"])
+        .args(&args.day_args)
+        .status();
+
+    match status {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = parse_args(args(&["05", "-y", "2015", "--release", "--", "--bench"])).unwrap();
+        assert_eq!(parsed.year, 2015);
+        assert_eq!(parsed.day, 5);
+        assert_eq!(parsed.cargo_args, args(&["--release"]));
+        assert_eq!(parsed.day_args, args(&["--bench"]));
+
+        let parsed = parse_args(args(&["7", "--year", "2020"])).unwrap();
+        assert_eq!(parsed.day, 7);
+        assert!(parsed.cargo_args.is_empty());
+        assert!(parsed.day_args.is_empty());
+    }
+
+    #[test]
+    fn test_parse_args_checked() {
+        let parsed = parse_args(args(&["05", "--release", "--checked", "--", "--bench"])).unwrap();
+        assert_eq!(parsed.cargo_args, args(&["--profile", "checked"]));
+        assert_eq!(parsed.day_args, args(&["--bench"]));
+    }
+
+    #[test]
+    fn test_parse_args_part() {
+        let parsed = parse_args(args(&["05", "--part", "1", "--", "--bench"])).unwrap();
+        assert!(parsed.cargo_args.is_empty());
+        assert_eq!(parsed.day_args, args(&["--part", "1", "--bench"]));
+    }
+}
@@ -0,0 +1,252 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    answers::{self, Answers},
+    input::{day_path, example_names},
+    puzzle, ANSI_BOLD, ANSI_RESET,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+};
+
+struct Args {
+    year: u16,
+    tests: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: advent_of_code::parse_year(&mut args)?,
+        tests: !args.contains("--no-tests"),
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TestStatus {
+    Passed,
+    Failed,
+    NotBuilt,
+}
+
+/// What exists of a day, as put there by `scaffold` and `download`.
+struct DayStatus {
+    day: u8,
+    scaffolded: bool,
+    input: bool,
+    puzzle: bool,
+    part_two_unlocked: bool,
+    examples: usize,
+    tests: Option<TestStatus>,
+    answers: [bool; 2],
+}
+
+fn module_name(year: u16, day: u8) -> String {
+    format!("{year}-{day:02}")
+}
+
+fn module_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from("src")
+        .join("bin")
+        .join(format!("{}.rs", module_name(year, day)))
+}
+
+/// The puzzle description only contains part two once part one is solved.
+fn is_part_two_unlocked(puzzle: &str) -> bool {
+    puzzle::split_parts(puzzle).1.is_some()
+}
+
+fn is_non_empty(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+fn day_status(year: u16, day: u8, answers: &Answers) -> DayStatus {
+    let puzzle = fs::read_to_string(day_path("puzzles", year, day, "md")).ok();
+    let examples = example_names(year, day).unwrap_or_default();
+
+    DayStatus {
+        day,
+        scaffolded: module_path(year, day).exists(),
+        input: is_non_empty(&day_path("inputs", year, day, "txt")),
+        puzzle: puzzle.is_some(),
+        part_two_unlocked: puzzle.as_deref().is_some_and(is_part_two_unlocked),
+        examples: examples.len(),
+        tests: None,
+        answers: [1, 2].map(|part| answers.get(year, day, part).is_some()),
+    }
+}
+
+/// A line of `cargo test --no-run --message-format=json`.
+#[derive(Deserialize)]
+struct Artifact {
+    reason: String,
+    target: Option<Target>,
+    executable: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+}
+
+/// Maps the binaries among `names` to their test executables.
+fn parse_test_executables(output: &str, names: &[String]) -> HashMap<String, PathBuf> {
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Artifact>(line).ok())
+        .filter(|artifact| artifact.reason == "compiler-artifact")
+        .filter_map(|artifact| Some((artifact.target?.name, artifact.executable?)))
+        .filter(|(name, _)| names.contains(name))
+        .collect()
+}
+
+/// Builds the tests of all scaffolded days at once, then runs each day's tests.
+fn run_tests(year: u16, days: &mut [DayStatus]) {
+    let names: Vec<String> = days
+        .iter()
+        .filter(|day| day.scaffolded)
+        .map(|day| module_name(year, day.day))
+        .collect();
+
+    if names.is_empty() {
+        return;
+    }
+
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command.args(["test", "--no-run", "--message-format=json"]);
+    for name in &names {
+        command.args(["--bin", name]);
+    }
+
+    let executables = match command.stderr(Stdio::null()).output() {
+        Ok(output) => parse_test_executables(&String::from_utf8_lossy(&output.stdout), &names),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    };
+
+    for day in days.iter_mut().filter(|day| day.scaffolded) {
+        let status = match executables.get(&module_name(year, day.day)) {
+            Some(executable) => {
+                let passed = Command::new(executable)
+                    .arg("--quiet")
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .status()
+                    .is_ok_and(|status| status.success());
+                if passed {
+                    TestStatus::Passed
+                } else {
+                    TestStatus::Failed
+                }
+            }
+            None => TestStatus::NotBuilt,
+        };
+        day.tests = Some(status);
+    }
+}
+
+fn mark(value: bool) -> &'static str {
+    if value {
+        "✓"
+    } else {
+        "·"
+    }
+}
+
+fn print_status(year: u16, days: &[DayStatus]) {
+    println!(
+        "{ANSI_BOLD}{year}    Scaffolded  Input  Puzzle  Part 2  Examples  Tests     Answers{ANSI_RESET}"
+    );
+
+    for day in days {
+        let tests = match day.tests {
+            Some(TestStatus::Passed) => "passed",
+            Some(TestStatus::Failed) => "failed",
+            Some(TestStatus::NotBuilt) => "no build",
+            None => "·",
+        };
+        let answers = match day.answers {
+            [true, true] => "★★",
+            [true, false] => "★",
+            _ => "·",
+        };
+
+        println!(
+            "Day {:02}  {:<10}  {:<5}  {:<6}  {:<6}  {:<8}  {:<8}  {}",
+            day.day,
+            mark(day.scaffolded),
+            mark(day.input),
+            mark(day.puzzle),
+            mark(day.part_two_unlocked),
+            day.examples,
+            tests,
+            answers
+        );
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let answers = match Answers::load(Path::new(answers::ANSWERS_PATH)) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {e}", answers::ANSWERS_PATH);
+            process::exit(1);
+        }
+    };
+
+    let mut days: Vec<DayStatus> = (1..=25)
+        .map(|day| day_status(args.year, day, &answers))
+        .collect();
+
+    if args.tests {
+        run_tests(args.year, &mut days);
+    }
+
+    print_status(args.year, &days);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_part_two_unlocked() {
+        assert!(is_part_two_unlocked(
+            "## \\--- Day 1: Calorie Counting ---\n...\n## \\--- Part Two ---\n"
+        ));
+        assert!(!is_part_two_unlocked(
+            "## \\--- Day 1: Calorie Counting ---\n..."
+        ));
+    }
+
+    #[test]
+    fn test_parse_test_executables() {
+        let output = r#"{"reason":"compiler-artifact","target":{"name":"2022-01","kind":["bin"]},"executable":"/target/debug/deps/2022_01-abc"}
+{"reason":"compiler-artifact","target":{"name":"advent_of_code","kind":["lib"]},"executable":null}
+{"reason":"compiler-artifact","target":{"name":"2022-02","kind":["bin"]},"executable":"/target/debug/deps/2022_02-def"}
+{"reason":"build-finished","success":true}"#;
+
+        let executables = parse_test_executables(output, &["2022-01".into()]);
+        assert_eq!(executables.len(), 1);
+        assert_eq!(
+            executables["2022-01"],
+            PathBuf::from("/target/debug/deps/2022_01-abc")
+        );
+    }
+}
@@ -0,0 +1,316 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    input::{day_path, example_names, Input},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long the files have to stay unchanged before a run starts, so that a burst of saves
+/// only triggers one run.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+struct Args {
+    year: u16,
+    day: u8,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: advent_of_code::parse_year(&mut args)?,
+        day: args.free_from_str()?,
+    })
+}
+
+/// The files a day depends on: its module, the helpers and its example and input files.
+fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/bin/{year}-{day:02}.rs")),
+        PathBuf::from("src/helpers.rs"),
+        day_path("inputs", year, day, "txt"),
+    ];
+
+    if let Ok(entries) = fs::read_dir("src/helpers") {
+        files.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+    }
+
+    for name in example_names(year, day).unwrap_or_default() {
+        match Input::example(year, day, &name) {
+            Input::Day { .. } => files.push(day_path("examples", year, day, "txt")),
+            Input::Path(path) => files.push(path),
+            _ => {}
+        }
+    }
+
+    files.sort();
+    files
+}
+
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+fn snapshot(year: u16, day: u8) -> Snapshot {
+    watched_files(year, day)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Blocks until a watched file changed and then stayed unchanged for `DEBOUNCE`.
+fn wait_for_change(year: u16, day: u8, last: &Snapshot) -> Snapshot {
+    let mut current = snapshot(year, day);
+    while &current == last {
+        thread::sleep(POLL_INTERVAL);
+        current = snapshot(year, day);
+    }
+
+    loop {
+        thread::sleep(DEBOUNCE);
+        let settled = snapshot(year, day);
+        if settled == current {
+            return settled;
+        }
+        current = settled;
+    }
+}
+
+fn cargo(args: &[&str]) -> Output {
+    match Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+        .args(args)
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// The lines of cargo's stderr that are not progress or config notes, e.g. compile errors.
+fn diagnostics(stderr: &str) -> Vec<&str> {
+    stderr
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            !line.is_empty()
+                && !line.starts_with("Compiling")
+                && !line.starts_with("Finished")
+                && !line.starts_with("warning: `")
+                && !line.starts_with("= help: if you need to support cargo")
+                && !line.starts_with('|')
+        })
+        .collect()
+}
+
+/// Sums up the `test result:` line and lists failed tests, e.g. `3 passed, 1 failed: tests::test_part_two`.
+fn summarize_tests(stdout: &str) -> String {
+    let count = |result: &str, label: &str| {
+        result
+            .split(';')
+            .find_map(|part| part.trim().strip_suffix(label)?.trim().parse::<u32>().ok())
+            .unwrap_or(0)
+    };
+
+    let Some(result) = stdout.lines().find(|line| line.starts_with("test result:")) else {
+        return "no test result".into();
+    };
+    let result = result.split_once('.').map_or(result, |(_, counts)| counts);
+
+    // `--quiet` prints no line per test, but both formats end with a list of the failures.
+    let failed: Vec<&str> = stdout
+        .rsplit_once("\nfailures:\n")
+        .map(|(_, failures)| {
+            failures
+                .lines()
+                .map_while(|line| line.strip_prefix("    "))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let summary = format!(
+        "{} passed, {} failed",
+        count(result, "passed"),
+        count(result, "failed")
+    );
+
+    match failed.is_empty() {
+        true => summary,
+        false => format!("{summary}: {}", failed.join(", ")),
+    }
+}
+
+/// Reads the answers from the JSON records printed by `--format json`.
+fn summarize_answers(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .map(|record| {
+            let elapsed = Duration::from_nanos(record["elapsed_ns"].as_u64().unwrap_or(0));
+            if record["phase"] == "parse" {
+                return format!("Parse {ANSI_ITALIC}({elapsed:.2?}){ANSI_RESET}");
+            }
+            let answer = match (record["answer"].as_str(), record["status"].as_str()) {
+                (Some(answer), _) if answer.contains('\n') => format!("\n{answer}"),
+                (Some(answer), _) => answer.to_string(),
+                (None, Some("panicked")) => {
+                    format!(
+                        "panicked: {}",
+                        record["error"].as_str().unwrap_or("no message")
+                    )
+                }
+                (None, Some("overflowed")) => "overflowed".into(),
+                _ => "not solved".into(),
+            };
+            format!(
+                "Part {}: {answer} {ANSI_ITALIC}({elapsed:.2?}){ANSI_RESET}",
+                record["part"]
+            )
+        })
+        .collect()
+}
+
+fn run(year: u16, day: u8) {
+    let name = format!("{year}-{day:02}");
+    let time = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() % 86400);
+    println!(
+        "{ANSI_BOLD}--- {name} at {:02}:{:02}:{:02} ---{ANSI_RESET}",
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    );
+
+    let build = cargo(&[
+        "test",
+        "--bin",
+        &name,
+        "--no-run",
+        "--message-format",
+        "short",
+    ]);
+    if !build.status.success() {
+        println!("✗ does not compile:");
+        for line in diagnostics(&String::from_utf8_lossy(&build.stderr)) {
+            println!("  {line}");
+        }
+        return;
+    }
+
+    let tests = cargo(&["test", "--bin", &name, "--quiet"]);
+    let mark = if tests.status.success() { "✓" } else { "✗" };
+    println!(
+        "{mark} tests: {}",
+        summarize_tests(&String::from_utf8_lossy(&tests.stdout))
+    );
+
+    let solution = cargo(&["run", "--bin", &name, "--quiet", "--", "--format", "json"]);
+    if solution.status.success() {
+        for answer in summarize_answers(&String::from_utf8_lossy(&solution.stdout)) {
+            println!("  {answer}");
+        }
+    } else {
+        println!("✗ solution failed:");
+        for line in diagnostics(&String::from_utf8_lossy(&solution.stderr)) {
+            println!("  {line}");
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let module = format!("src/bin/{}-{:02}.rs", args.year, args.day);
+    if !Path::new(&module).exists() {
+        eprintln!("\"{module}\" does not exist, scaffold the day first.");
+        process::exit(1);
+    }
+
+    println!(
+        "🎄 Watching day {:02} of {}, stop with Ctrl-C.",
+        args.day, args.year
+    );
+
+    let mut last = snapshot(args.year, args.day);
+    run(args.year, args.day);
+
+    loop {
+        last = wait_for_change(args.year, args.day, &last);
+        run(args.year, args.day);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_tests() {
+        // the output of `cargo test --quiet`.
+        let stdout = "\nrunning 2 tests\n\
+                      . 1/2\n\
+                      tests::test_part_two --- FAILED\n\n\
+                      failures:\n\n\
+                      ---- tests::test_part_two stdout ----\n\n\
+                      thread 'tests::test_part_two' panicked at src/bin/2022-01.rs:9:26:\n\
+                      assertion `left == right` failed\n  left: 1\n right: 2\n\
+                      note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n\n\n\
+                      failures:\n    tests::test_part_two\n\n\
+                      test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.01s\n";
+        assert_eq!(
+            summarize_tests(stdout),
+            "1 passed, 1 failed: tests::test_part_two"
+        );
+
+        let stdout = "test result: ok. 2 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out\n";
+        assert_eq!(summarize_tests(stdout), "2 passed, 0 failed");
+    }
+
+    #[test]
+    fn test_summarize_answers() {
+        let stdout = concat!(
+            r#"{"year":2022,"day":1,"phase":"parse","elapsed_ns":500}"#,
+            "\n",
+            r#"{"year":2022,"day":1,"part":1,"answer":"24000","solved":true,"status":"solved","elapsed_ns":1000}"#,
+            "\n",
+            r#"{"year":2022,"day":1,"part":2,"answer":null,"solved":false,"status":"not_solved","elapsed_ns":0}"#,
+            "\n",
+            r#"{"year":2022,"day":1,"part":2,"answer":null,"solved":false,"status":"panicked","error":"oops","elapsed_ns":0}"#
+        );
+        let answers = summarize_answers(stdout);
+
+        assert_eq!(answers.len(), 4);
+        assert!(answers[0].starts_with("Parse "));
+        assert!(answers[1].starts_with("Part 1: 24000 "));
+        assert!(answers[2].starts_with("Part 2: not solved "));
+        assert!(answers[3].starts_with("Part 2: panicked: oops "));
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let stderr = "   Compiling advent_of_code v0.8.0\n\
+                      src/bin/2022-01.rs:3:5: error[E0425]: cannot find value `x` in this scope\n\
+                      error: could not compile `advent_of_code` (bin \"2022-01\" test) due to 1 previous error\n";
+        assert_eq!(diagnostics(stderr).len(), 2);
+    }
+}
@@ -0,0 +1,220 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Reads the block letters some puzzles draw as their answer.
+//! example: `advent_of_code::helpers::ocr::parse(&screen)` turns
+//!
+//! ```text
+//! #..#.####
+//! #..#.#...
+//! ####.###.
+//! #..#.#...
+//! #..#.#...
+//! #..#.####
+//! ```
+//!
+//! into `Some("HE")`. Lit pixels are `#`, every other character is unlit.
+
+/// The 4x6 letters, as used e.g. by 2016 day 8, 2019 days 8 and 11 and 2022 day 10.
+const SMALL_GLYPHS: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// The 6x10 letters, as used e.g. by 2018 day 10.
+const LARGE_GLYPHS: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+type Pixels = Vec<Vec<bool>>;
+
+fn to_pixels(grid: &str) -> Pixels {
+    grid.lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect()
+}
+
+fn is_blank_column(pixels: &Pixels, column: usize) -> bool {
+    pixels
+        .iter()
+        .all(|row| !row.get(column).copied().unwrap_or(false))
+}
+
+/// Splits a grid into its letters at the columns that have no lit pixels.
+fn split_glyphs(pixels: &Pixels) -> Vec<Pixels> {
+    let width = pixels.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut glyphs = vec![];
+    let mut start = None;
+    for column in 0..=width {
+        match (start, column == width || is_blank_column(pixels, column)) {
+            (None, false) => start = Some(column),
+            (Some(from), true) => {
+                glyphs.push(
+                    pixels
+                        .iter()
+                        .map(|row| (from..column).map(|c| row.get(c) == Some(&true)).collect())
+                        .collect(),
+                );
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    glyphs
+}
+
+fn recognize(glyph: &Pixels, alphabet: &[(char, &str)]) -> Option<char> {
+    alphabet.iter().find_map(|(letter, pattern)| {
+        let pattern = split_glyphs(&to_pixels(pattern));
+        (pattern.first() == Some(glyph)).then_some(*letter)
+    })
+}
+
+/// Reads the letters of a 6 or 10 rows high grid. Returns `None` if the grid has a different
+/// height or contains a shape that is not a known letter.
+pub fn parse(grid: &str) -> Option<String> {
+    let pixels: Pixels = to_pixels(grid.trim_matches('\n'));
+
+    let alphabet = match pixels.len() {
+        6 => SMALL_GLYPHS,
+        10 => LARGE_GLYPHS,
+        _ => return None,
+    };
+
+    let letters: Option<String> = split_glyphs(&pixels)
+        .iter()
+        .map(|glyph| recognize(glyph, alphabet))
+        .collect();
+
+    letters.filter(|letters| !letters.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_small() {
+        let grid = "\
+###..#....###...##..####.###...##....##.
+#..#.#....#..#.#..#.#....#..#.#..#....#.
+#..#.#....###..#....###..###..#..#....#.
+###..#....#..#.#....#....#..#.####....#.
+#.#..#....#..#.#..#.#....#..#.#..#.#..#.
+#..#.####.###...##..####.###..#..#..##..";
+        assert_eq!(parse(grid), Some("RLBCEBAJ".into()));
+    }
+
+    #[test]
+    fn test_parse_small_variable_width() {
+        let grid = "\
+#...#..###.
+#...#...#..
+.#.#....#..
+..#.....#..
+..#.....#..
+..#....###.";
+        assert_eq!(parse(grid), Some("YI".into()));
+    }
+
+    #[test]
+    fn test_parse_large() {
+        let grid = "\
+#....#..######
+#....#..#.....
+#....#..#.....
+#....#..#.....
+######..#####.
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(parse(grid), Some("HE".into()));
+    }
+
+    #[test]
+    fn test_parse_unknown() {
+        assert_eq!(parse("#\n#\n#\n#\n#\n#"), None);
+        assert_eq!(parse("#..#\n####"), None);
+        assert_eq!(parse("....\n....\n....\n....\n....\n...."), None);
+    }
+}
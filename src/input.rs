@@ -0,0 +1,237 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// The file of a day in one of the per-year folders, e.g. `src/inputs/2022/01.txt`.
+pub fn day_path(folder: &str, year: u16, day: u8, extension: &str) -> PathBuf {
+    PathBuf::from("src")
+        .join(folder)
+        .join(year.to_string())
+        .join(format!("{day:02}.{extension}"))
+}
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    /// `src/<folder>/<year>/<day>.txt`, relative to the current directory.
+    Day {
+        folder: String,
+        year: u16,
+        day: u8,
+    },
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Input {
+    pub fn day(folder: &str, year: u16, day: u8) -> Input {
+        Input::Day {
+            folder: folder.into(),
+            year,
+            day,
+        }
+    }
+
+    /// The example `name` of a day: `src/examples/<year>/<day>/<name>.txt` if that exists,
+    /// otherwise `src/examples/<year>/<day>-<name>.txt`. The empty name is `src/examples/<year>/<day>.txt`.
+    pub fn example(year: u16, day: u8, name: &str) -> Input {
+        if name.is_empty() {
+            return Input::day("examples", year, day);
+        }
+
+        let examples = examples_dir(year);
+        let in_folder = examples
+            .join(format!("{day:02}"))
+            .join(format!("{name}.txt"));
+        if in_folder.exists() {
+            Input::Path(in_folder)
+        } else {
+            Input::Path(examples.join(format!("{day:02}-{name}.txt")))
+        }
+    }
+
+    /// Reads `--input <path>` (or `--input -` for stdin), defaulting to `src/inputs/<year>/<day>.txt`.
+    pub fn from_args(
+        mut args: pico_args::Arguments,
+        year: u16,
+        day: u8,
+    ) -> Result<Input, pico_args::Error> {
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+        Ok(match input.as_deref() {
+            None => Input::day("inputs", year, day),
+            Some("-") => Input::Stdin,
+            Some(path) => Input::Path(path.into()),
+        })
+    }
+
+    /// The path this input is read from, as shown in errors.
+    pub fn path(&self) -> String {
+        match self {
+            Input::Day { folder, year, day } => {
+                let relative = day_path(folder, *year, *day, "txt");
+                env::current_dir()
+                    .map(|cwd| cwd.join(&relative))
+                    .unwrap_or(relative)
+                    .display()
+                    .to_string()
+            }
+            Input::Path(path) => path.display().to_string(),
+            Input::Stdin => "<stdin>".into(),
+            Input::Inline(_) => "<inline>".into(),
+        }
+    }
+
+    pub fn load(&self) -> Result<String, InputError> {
+        let result = match self {
+            Input::Day { .. } => fs::read_to_string(self.path()),
+            Input::Path(path) => fs::read_to_string(path),
+            Input::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents).map(|_| contents)
+            }
+            Input::Inline(contents) => Ok(contents.clone()),
+        };
+
+        result.map_err(|error| InputError {
+            path: self.path(),
+            error,
+        })
+    }
+}
+
+fn examples_dir(year: u16) -> PathBuf {
+    PathBuf::from("src").join("examples").join(year.to_string())
+}
+
+/// Lists the names of a day's example files in `src/examples/<year>`, see `Input::example`.
+/// A year without examples has none.
+pub fn example_names(year: u16, day: u8) -> io::Result<Vec<String>> {
+    match example_names_in(&examples_dir(year), day) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        names => names,
+    }
+}
+
+fn example_names_in(examples: &Path, day: u8) -> io::Result<Vec<String>> {
+    let day = format!("{day:02}");
+    let mut names = vec![];
+
+    for entry in fs::read_dir(examples)? {
+        let path = entry?.path();
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        if path.is_dir() && stem == day {
+            for example in fs::read_dir(&path)? {
+                let example = example?.path();
+                if example.extension().is_some_and(|ext| ext == "txt") {
+                    if let Some(name) = example.file_stem().and_then(|stem| stem.to_str()) {
+                        names.push(name.to_string());
+                    }
+                }
+            }
+        } else if path.extension().is_some_and(|ext| ext == "txt") {
+            if stem == day {
+                names.push(String::new());
+            } else if let Some(name) = stem.strip_prefix(&format!("{day}-")) {
+                names.push(name.to_string());
+            }
+        }
+    }
+
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: String,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not read input \"{}\": {}", self.path, self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsString;
+
+    fn args(args: &[&str]) -> pico_args::Arguments {
+        pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(
+            Input::from_args(args(&[]), 2022, 3).unwrap(),
+            Input::day("inputs", 2022, 3)
+        );
+        assert_eq!(
+            Input::from_args(args(&["--input", "-"]), 2022, 3).unwrap(),
+            Input::Stdin
+        );
+        assert_eq!(
+            Input::from_args(args(&["--bench", "--input", "big.txt"]), 2022, 3).unwrap(),
+            Input::Path("big.txt".into())
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        assert_eq!(Input::Inline("1\n2\n".into()).load().unwrap(), "1\n2\n");
+        assert!(Input::day("examples", 2022, 1)
+            .load()
+            .unwrap()
+            .starts_with("1000\n"));
+
+        assert_eq!(Input::example(2022, 1, ""), Input::day("examples", 2022, 1));
+        assert_eq!(
+            Input::example(2022, 1, "a"),
+            Input::Path(PathBuf::from("src/examples/2022/01-a.txt"))
+        );
+
+        let error = Input::Path("does/not/exist.txt".into()).load().unwrap_err();
+        assert_eq!(error.path, "does/not/exist.txt");
+        assert_eq!(error.error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_example_names() {
+        let dir = env::temp_dir().join(format!("aoc-examples-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("07")).unwrap();
+        for file in [
+            "07.txt",
+            "07-b.txt",
+            "07/a.txt",
+            "07/notes.md",
+            "17-a.txt",
+            "06.txt",
+        ] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        assert_eq!(
+            example_names_in(&dir, 7).unwrap(),
+            vec!["".to_string(), "a".to_string(), "b".to_string()]
+        );
+        assert_eq!(example_names_in(&dir, 17).unwrap(), vec!["a".to_string()]);
+        assert!(example_names_in(&dir, 8).unwrap().is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,232 +3,852 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use allocations::AllocStats;
+use input::Input;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
 use std::env;
-use std::fs;
-
+use std::fmt::Display;
+use std::hint::black_box;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::str::FromStr;
+use std::sync::{Arc, Once};
+use std::time::{Duration, Instant};
+
+pub mod allocations;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod helpers;
+pub mod input;
+pub mod puzzle;
+pub mod readme_benchmarks;
+pub mod submit;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// A day's input after its parse phase, shared by both parts.
+pub type Parsed = Arc<dyn Any + Send + Sync>;
+
+/// A day's puzzle solution, callable in-process by the all-days runner.
+/// Implemented for each day in `src/bin` by the `solution!` macro.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    /// Whether the day has a parse phase. Without one, `parse` keeps the input as it is.
+    fn has_parse(&self) -> bool;
+    fn parse(&self, input: &str) -> Parsed;
+    fn part_one(&self, parsed: &Parsed) -> Option<String>;
+    fn part_two(&self, parsed: &Parsed) -> Option<String>;
+}
+
+/// Registers a day's `part_one` and `part_two` functions with the all-days runner.
+/// Also defines the `YEAR` and `DAY` constants that `solve!` reports results for.
+/// example: `advent_of_code::solution!(2022, 1, "Calorie Counting");`
+///
+/// Days with a parse phase pass their `parse` function as well, the parts then take its result.
+/// example: `advent_of_code::solution!(2022, 5, "Supply Stacks", parse);`
+#[macro_export]
+macro_rules! solution {
+    ($year:expr, $day:expr) => {
+        advent_of_code::solution!($year, $day, "");
+    };
+    ($year:expr, $day:expr, $title:expr) => {
+        advent_of_code::solution!(@impl $year, $day, $title, false, str::to_string, call_part_with_input);
+    };
+    ($year:expr, $day:expr, $title:expr, $parse:ident) => {
+        advent_of_code::solution!(@impl $year, $day, $title, true, $parse, call_part_with_parsed);
+    };
+    (@impl $year:expr, $day:expr, $title:expr, $has_parse:expr, $parse:expr, $call:ident) => {
+        pub const YEAR: u16 = $year;
+        pub const DAY: u8 = $day;
+
+        pub struct Solution;
+
+        impl advent_of_code::Solution for Solution {
+            fn year(&self) -> u16 {
+                YEAR
+            }
+
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            fn title(&self) -> &'static str {
+                $title
+            }
+
+            fn has_parse(&self) -> bool {
+                $has_parse
+            }
+
+            fn parse(&self, input: &str) -> advent_of_code::Parsed {
+                std::sync::Arc::new($parse(input))
+            }
+
+            fn part_one(&self, parsed: &advent_of_code::Parsed) -> Option<String> {
+                advent_of_code::$call(part_one, parsed)
+            }
+
+            fn part_two(&self, parsed: &advent_of_code::Parsed) -> Option<String> {
+                advent_of_code::$call(part_two, parsed)
+            }
+        }
+    };
+}
+
+/// Calls a part of a day without parse phase, whose `Parsed` is the input itself.
+#[doc(hidden)]
+pub fn call_part_with_input<T: Display>(
+    func: impl Fn(&str) -> Option<T>,
+    parsed: &Parsed,
+) -> Option<String> {
+    call_part_with_parsed(|input: &String| func(input), parsed)
+}
+
+/// Calls a part of a day with the result of its parse phase.
+#[doc(hidden)]
+pub fn call_part_with_parsed<P: 'static, T: Display>(
+    func: impl Fn(&P) -> Option<T>,
+    parsed: &Parsed,
+) -> Option<String> {
+    let parsed = parsed
+        .downcast_ref::<P>()
+        .expect("parsed input does not match the type the part takes");
+    func(parsed).map(|result| result.to_string())
+}
+
+/// Runs a day's parse phase, timing and printing it like a part.
+/// The result is passed to `solve!` in place of the input.
+/// example: `let parsed = advent_of_code::parse!(parse, input);`
+#[macro_export]
+macro_rules! parse {
+    ($parser:ident, $input:expr) => {{
+        let options = advent_of_code::run_options();
+        let input = $input;
+        let timer = std::time::Instant::now();
+        let parsed = $parser(input);
+        let mut result = advent_of_code::ParseResult::new(YEAR, DAY, timer.elapsed());
+
+        if let Some(config) = options.bench {
+            result.bench = Some(advent_of_code::bench(
+                || std::hint::black_box($parser(std::hint::black_box(input))),
+                config,
+            ));
+        }
+
+        if options.format == advent_of_code::OutputFormat::Text {
+            advent_of_code::print_parse_header();
+        }
+        advent_of_code::print_parse_result(&result, options.format);
+        parsed
+    }};
+}
+
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+        use advent_of_code::{OutputFormat, PartResult, RunOptions};
         use std::fmt::Display;
+        use std::hint::black_box;
         use std::time::Instant;
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+        fn print_result<I: ?Sized, T: Display>(
+            func: impl Fn(&I) -> Option<T>,
+            input: &I,
+            options: &RunOptions,
+        ) {
+            let ((result, elapsed), allocations) = advent_of_code::allocations::measure(|| {
+                let timer = Instant::now();
+                let result = advent_of_code::catch_overflow(|| func(input));
+                (result, timer.elapsed())
+            });
+            let mut result = match result {
+                Some(answer) => {
+                    let answer = answer.map(|answer| answer.to_string());
+                    PartResult::new(YEAR, DAY, $part, answer, elapsed)
                 }
+                None => PartResult::overflowed(YEAR, DAY, $part, elapsed),
+            };
+            result.allocations = allocations;
+
+            if let Some(config) = options.bench.filter(|_| result.solved) {
+                result.bench = Some(advent_of_code::bench(
+                    || black_box(func(black_box(input))),
+                    config,
+                ));
+            }
+
+            advent_of_code::print_result(&result, options.format);
+
+            if options.submit == Some($part) {
+                advent_of_code::submit::submit_result(YEAR, &result);
             }
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        let options = advent_of_code::run_options();
+        if options.runs_part($part) {
+            if options.format == OutputFormat::Text {
+                advent_of_code::print_part_header($part);
+            }
+            print_result($solver, $input, &options);
+        }
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+impl FromStr for OutputFormat {
+    type Err = String;
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "unknown output format \"{s}\", expected \"text\" or \"json\""
+            )),
+        }
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Command line options understood by every day's `solve!` calls.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunOptions {
+    pub format: OutputFormat,
+    pub bench: Option<BenchConfig>,
+    pub submit: Option<u8>,
+    pub part: Option<u8>,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+impl RunOptions {
+    /// Whether `part` is selected by `--part` and `--submit`.
+    pub fn runs_part(&self, part: u8) -> bool {
+        [self.part, self.submit]
+            .iter()
+            .all(|selected| selected.is_none_or(|selected| selected == part))
+    }
+}
+
+/// Parses the value of `--part`, which has to be `1` or `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{s}\", expected 1 or 2")),
+    }
+}
+
+fn parse_run_options(mut args: pico_args::Arguments) -> Result<RunOptions, pico_args::Error> {
+    let bench_env = env::var("AOC_BENCH").is_ok_and(|val| !val.is_empty() && val != "0");
+    let bench = args.contains("--bench") || bench_env;
+    let iterations = args.opt_value_from_str("--iterations")?;
+    let warmup = args.opt_value_from_str("--warmup")?;
+
+    Ok(RunOptions {
+        format: args
+            .opt_value_from_str("--format")?
+            .unwrap_or(OutputFormat::Text),
+        bench: bench.then(|| BenchConfig {
+            iterations: iterations.unwrap_or(BenchConfig::DEFAULT.iterations),
+            warmup: warmup.unwrap_or(BenchConfig::DEFAULT.warmup),
+        }),
+        submit: args.opt_value_from_fn("--submit", parse_part)?,
+        part: args.opt_value_from_fn("--part", parse_part)?,
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads `RunOptions` from the command line, exiting on invalid arguments.
+/// `--format text|json` selects the output, `--bench` (or `AOC_BENCH=1`) enables benchmarking,
+/// `--part <part>` runs only that part, `--submit <part>` runs only that part and submits its answer.
+pub fn run_options() -> RunOptions {
+    match parse_run_options(pico_args::Arguments::from_env()) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartStatus {
+    Solved,
+    NotSolved,
+    TimedOut,
+    Overflowed,
+    Panicked,
+    MissingInput,
+    MissingSolution,
+}
 
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+/// The outcome of running one part of a day, as emitted by `--format json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub solved: bool,
+    pub status: PartStatus,
+    /// The panic message of a part that panicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub elapsed_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocStats>,
+}
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+impl PartResult {
+    pub fn new(year: u16, day: u8, part: u8, answer: Option<String>, elapsed: Duration) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            solved: answer.is_some(),
+            status: match answer {
+                Some(_) => PartStatus::Solved,
+                None => PartStatus::NotSolved,
+            },
+            answer,
+            error: None,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            bench: None,
+            allocations: None,
+        }
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+    /// A part that was given up on after running for `limit`.
+    pub fn timed_out(year: u16, day: u8, part: u8, limit: Duration) -> Self {
+        PartResult {
+            status: PartStatus::TimedOut,
+            ..PartResult::new(year, day, part, None, limit)
+        }
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    /// A part that panicked because of an arithmetic overflow after running for `elapsed`.
+    pub fn overflowed(year: u16, day: u8, part: u8, elapsed: Duration) -> Self {
+        PartResult {
+            status: PartStatus::Overflowed,
+            ..PartResult::new(year, day, part, None, elapsed)
+        }
+    }
+
+    /// A part that panicked with `message` after running for `elapsed`.
+    pub fn panicked(year: u16, day: u8, part: u8, message: String, elapsed: Duration) -> Self {
+        PartResult {
+            status: PartStatus::Panicked,
+            error: Some(message),
+            ..PartResult::new(year, day, part, None, elapsed)
+        }
+    }
+
+    /// A part that was not run, either because the day has no input or no solution.
+    pub fn missing(year: u16, day: u8, part: u8, status: PartStatus) -> Self {
+        PartResult {
+            status,
+            ..PartResult::new(year, day, part, None, Duration::ZERO)
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns)
+    }
+}
+
+/// The message a panic was raised with, if it has one.
+pub fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    match payload.downcast_ref::<&'static str>() {
+        Some(message) => Some(message),
+        None => payload.downcast_ref::<String>().map(String::as_str),
     }
 }
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
+/// Whether a panic was raised by an overflow check, e.g. "attempt to add with overflow".
+pub fn is_overflow(payload: &(dyn Any + Send)) -> bool {
+    panic_message(payload).is_some_and(|message| message.ends_with("with overflow"))
+}
+
+/// Stops overflow panics from being printed, as they are reported as `PartStatus::Overflowed`.
+/// Other panics are still printed.
+pub fn quiet_overflow_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !is_overflow(info.payload()) {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs a part, returning `None` if it panicked because of an arithmetic overflow.
+/// Overflow checks are only on in debug builds and with the `checked` profile.
+/// Other panics are passed on.
+pub fn catch_overflow<T>(func: impl FnOnce() -> T) -> Option<T> {
+    quiet_overflow_panics();
+    match panic::catch_unwind(AssertUnwindSafe(func)) {
+        Ok(result) => Some(result),
+        Err(payload) if is_overflow(&*payload) => None,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Parse,
+}
+
+/// The time spent in a day's parse phase, as emitted by `--format json`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ParseResult {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub elapsed_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+}
+
+impl ParseResult {
+    pub fn new(year: u16, day: u8, elapsed: Duration) -> Self {
+        ParseResult {
+            year,
+            day,
+            phase: Phase::Parse,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            bench: None,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub iterations: u32,
+    pub warmup: u32,
+}
+
+impl BenchConfig {
+    pub const DEFAULT: BenchConfig = BenchConfig {
+        iterations: 100,
+        warmup: 10,
     };
+}
+
+/// Timing statistics over the timed iterations of a benchmark, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BenchStats {
+    pub iterations: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut samples: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        samples.sort_unstable();
+
+        let n = samples.len();
+        if n == 0 {
+            return BenchStats {
+                iterations: 0,
+                min_ns: 0,
+                median_ns: 0,
+                mean_ns: 0,
+                stddev_ns: 0,
+                p95_ns: 0,
+            };
+        }
 
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
+        let median_ns = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        let mean = samples.iter().map(|&s| s as f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|&s| (s as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        // nearest-rank percentile.
+        let p95_rank = (n as f64 * 0.95).ceil() as usize;
+
+        BenchStats {
+            iterations: n as u32,
+            min_ns: samples[0],
+            median_ns,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+            p95_ns: samples[p95_rank.max(1) - 1],
+        }
     }
+}
+
+/// Runs `func` for `config.warmup` untimed and `config.iterations` timed iterations.
+pub fn bench<T>(mut func: impl FnMut() -> T, config: BenchConfig) -> BenchStats {
+    for _ in 0..config.warmup {
+        black_box(func());
+    }
+
+    let samples: Vec<Duration> = (0..config.iterations)
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(func());
+            timer.elapsed()
+        })
+        .collect();
+
+    BenchStats::from_samples(&samples)
+}
+
+pub fn print_part_header(part: u8) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+}
+
+pub fn print_parse_header() {
+    println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+}
 
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
+pub fn print_parse_result(result: &ParseResult, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => {
+            println!(
+                "{}(elapsed: {:.2?}){}",
+                ANSI_ITALIC,
+                result.elapsed(),
+                ANSI_RESET
+            );
+            if let Some(stats) = &result.bench {
+                print_bench_stats(stats);
+            }
+        }
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(result).unwrap());
+        }
+    }
+}
+
+pub fn print_result(result: &PartResult, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => match &result.answer {
+            None if result.status == PartStatus::TimedOut => {
+                println!("timed out after {:.2?}.", result.elapsed())
+            }
+            None if result.status == PartStatus::Overflowed => {
+                println!("overflowed in part {}.", result.part)
+            }
+            None if result.status == PartStatus::Panicked => {
+                println!(
+                    "panicked in part {}: {}",
+                    result.part,
+                    result.error.as_deref().unwrap_or("no message")
+                )
+            }
+            Some(answer) => {
+                // multi-line answers (e.g. block letters) start on their own line.
+                if answer.contains('\n') {
+                    println!(
+                        "{}(elapsed: {:.2?}){}",
+                        ANSI_ITALIC,
+                        result.elapsed(),
+                        ANSI_RESET
+                    );
+                    println!("{}", answer.trim_end_matches('\n'));
+                } else {
+                    println!(
+                        "{} {}(elapsed: {:.2?}){}",
+                        answer,
+                        ANSI_ITALIC,
+                        result.elapsed(),
+                        ANSI_RESET
+                    );
+                }
+                if let Some(stats) = &result.allocations {
+                    print_alloc_stats(stats);
                 }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
+                if let Some(stats) = &result.bench {
+                    print_bench_stats(stats);
+                }
+            }
+            None => {
+                println!("not solved.")
             }
+        },
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(result).unwrap());
         }
     }
+}
+
+fn print_alloc_stats(stats: &AllocStats) {
+    println!(
+        "{}allocations: {} ({} total, {} peak){}",
+        ANSI_ITALIC,
+        stats.allocations,
+        format_bytes(stats.bytes),
+        format_bytes(stats.peak_bytes),
+        ANSI_RESET
+    );
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes}B"),
+        1024..=1_048_575 => format!("{:.2}KiB", bytes as f64 / 1024_f64),
+        _ => format!("{:.2}MiB", bytes as f64 / 1_048_576_f64),
+    }
+}
+
+fn print_bench_stats(stats: &BenchStats) {
+    let ns = Duration::from_nanos;
+    println!(
+        "{}min {:.2?} · median {:.2?} · mean {:.2?} ± {:.2?} · p95 {:.2?} ({} runs){}",
+        ANSI_ITALIC,
+        ns(stats.min_ns),
+        ns(stats.median_ns),
+        ns(stats.mean_ns),
+        ns(stats.stddev_ns),
+        ns(stats.p95_ns),
+        stats.iterations,
+        ANSI_RESET
+    );
+}
+
+/// The year used when no `--year` is given: `AOC_YEAR` from the environment,
+/// which `.cargo/config` sets for every cargo command.
+pub fn default_year() -> Option<u16> {
+    env::var("AOC_YEAR")
+        .ok()
+        .or_else(|| option_env!("AOC_YEAR").map(String::from))
+        .and_then(|year| year.parse().ok())
+}
 
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
+/// Reads `--year/-y` from `args`, falling back to `default_year`.
+pub fn parse_year(args: &mut pico_args::Arguments) -> Result<u16, pico_args::Error> {
+    match args.opt_value_from_str(["-y", "--year"])? {
+        Some(year) => Ok(year),
+        None => default_year().ok_or(pico_args::Error::MissingOption(["-y", "--year"].into())),
     }
+}
+
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
+}
+
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> io::Result<String> {
+    Input::day(folder, year, day).load().map_err(|e| e.error)
+}
+
+/// Reads a day's example file `name`, see `Input::example`. Use `""` for `src/examples/<year>/<day>.txt`.
+pub fn read_example(year: u16, day: u8, name: &str) -> String {
+    Input::example(year, day, name)
+        .load()
+        .expect("could not open example file")
+}
+
+/// Reads the puzzle input of a day binary, exiting if it cannot be read.
+/// `--input <path>` reads another file and `--input -` reads stdin instead of `src/inputs/<year>/<day>.txt`.
+pub fn read_input(year: u16, day: u8) -> String {
+    let input = match Input::from_args(pico_args::Arguments::from_env(), year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+    match input.load() {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
     }
+}
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+    #[test]
+    fn test_parse_output_format() {
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
 
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
+    #[test]
+    fn test_parse_run_options() {
+        let args = |args: &[&str]| {
+            pico_args::Arguments::from_vec(args.iter().map(|arg| arg.into()).collect())
+        };
+
+        let options = parse_run_options(args(&[])).unwrap();
+        assert_eq!(options.format, OutputFormat::Text);
+        assert_eq!(options.bench, None);
+
+        let options = parse_run_options(args(&["--bench", "--iterations", "5"])).unwrap();
+        assert_eq!(
+            options.bench,
+            Some(BenchConfig {
+                iterations: 5,
+                warmup: BenchConfig::DEFAULT.warmup
+            })
         );
 
-        let output = call_aoc_cli(&args)?;
+        let options = parse_run_options(args(&["--part", "2"])).unwrap();
+        assert_eq!(options.part, Some(2));
+        assert!(!options.runs_part(1));
+        assert!(options.runs_part(2));
 
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
-        }
+        let options = parse_run_options(args(&["--submit", "1"])).unwrap();
+        assert!(options.runs_part(1));
+        assert!(!options.runs_part(2));
+
+        assert!(parse_run_options(args(&["--part", "3"])).is_err());
     }
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
+    #[test]
+    fn test_parse_year() {
+        let args = |args: &[&str]| {
+            pico_args::Arguments::from_vec(args.iter().map(|arg| arg.into()).collect())
+        };
+
+        assert_eq!(parse_year(&mut args(&["-y", "2015"])).ok(), Some(2015));
+        assert_eq!(parse_year(&mut args(&["--year", "2020"])).ok(), Some(2020));
+        assert_eq!(parse_year(&mut args(&[])).ok(), default_year());
     }
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
+    #[test]
+    fn test_bench_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+        let stats = BenchStats::from_samples(&samples);
+
+        assert_eq!(stats.iterations, 20);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 10);
+        assert_eq!(stats.mean_ns, 11);
+        assert_eq!(stats.stddev_ns, 6);
+        assert_eq!(stats.p95_ns, 19);
     }
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
+    #[test]
+    fn test_bench_runs_every_iteration() {
+        let mut calls = 0;
+        let stats = bench(
+            || calls += 1,
+            BenchConfig {
+                iterations: 7,
+                warmup: 3,
+            },
+        );
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
+        assert_eq!(calls, 10);
+        assert_eq!(stats.iterations, 7);
+    }
 
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    // release builds wrap instead of panicking, e.g. under `cargo test --release`.
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_catch_overflow() {
+        assert_eq!(catch_overflow(|| black_box(254_u8) + 1), Some(255));
+        assert_eq!(catch_overflow(|| black_box(255_u8) + 1), None);
+        assert_eq!(catch_overflow(|| black_box(i64::MIN) - 1), None);
 
-        cmd_args
+        let other = panic::catch_unwind(|| catch_overflow(|| panic!("not an overflow")));
+        assert!(other.is_err());
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
+    #[test]
+    fn test_call_part_with_wide_answers() {
+        let parsed: Parsed = Arc::new(String::from("3"));
+
+        let part_u128 = |_: &str| Some(u128::MAX);
+        let part_i64 = |input: &str| Some(-input.parse::<i64>().unwrap() * 1_000_000_000_000);
+        assert_eq!(
+            call_part_with_input(part_u128, &parsed),
+            Some("340282366920938463463374607431768211455".into())
+        );
+        assert_eq!(
+            call_part_with_input(part_i64, &parsed),
+            Some("-3000000000000".into())
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 * 1_048_576), "3.00MiB");
+    }
+
+    #[test]
+    fn test_part_result_json() {
+        let solved = PartResult::new(2022, 1, 2, Some("45000".into()), Duration::from_nanos(74));
+        assert_eq!(
+            serde_json::to_string(&solved).unwrap(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"45000","solved":true,"status":"solved","elapsed_ns":74}"#
+        );
+
+        let unsolved = PartResult::new(2022, 8, 1, None, Duration::from_micros(3));
+        assert_eq!(
+            serde_json::to_string(&unsolved).unwrap(),
+            r#"{"year":2022,"day":8,"part":1,"answer":null,"solved":false,"status":"not_solved","elapsed_ns":3000}"#
+        );
 
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
+        let timed_out = PartResult::timed_out(2022, 5, 2, Duration::from_secs(1));
+        assert_eq!(
+            serde_json::to_string(&timed_out).unwrap(),
+            r#"{"year":2022,"day":5,"part":2,"answer":null,"solved":false,"status":"timed_out","elapsed_ns":1000000000}"#
+        );
+
+        let panicked =
+            PartResult::panicked(2022, 3, 2, "index out of bounds".into(), Duration::ZERO);
+        assert_eq!(
+            serde_json::to_string(&panicked).unwrap(),
+            r#"{"year":2022,"day":3,"part":2,"answer":null,"solved":false,"status":"panicked","error":"index out of bounds","elapsed_ns":0}"#
+        );
+
+        let missing = PartResult::missing(2022, 9, 1, PartStatus::MissingSolution);
+        assert_eq!(
+            serde_json::to_string(&missing).unwrap(),
+            r#"{"year":2022,"day":9,"part":1,"answer":null,"solved":false,"status":"missing_solution","elapsed_ns":0}"#
+        );
+
+        let overflowed = PartResult::overflowed(2022, 3, 1, Duration::from_nanos(10));
+        assert_eq!(
+            serde_json::to_string(&overflowed).unwrap(),
+            r#"{"year":2022,"day":3,"part":1,"answer":null,"solved":false,"status":"overflowed","elapsed_ns":10}"#
+        );
+
+        let parse = ParseResult::new(2015, 5, Duration::from_micros(3));
+        assert_eq!(
+            serde_json::to_string(&parse).unwrap(),
+            r#"{"year":2015,"day":5,"phase":"parse","elapsed_ns":3000}"#
+        );
     }
 }
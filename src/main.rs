@@ -2,44 +2,856 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{self, Answers};
+use advent_of_code::{benchmark_history, readme_benchmarks};
+use advent_of_code::{
+    OutputFormat, ParseResult, PartResult, PartStatus, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+mod days {
+    include!(concat!(env!("OUT_DIR"), "/days.rs"));
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+struct Args {
+    year: u16,
+    format: OutputFormat,
+    check: bool,
+    time: bool,
+    history: HistoryArgs,
+    days: Vec<RangeInclusive<u8>>,
+    config: RunConfig,
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: advent_of_code::parse_year(&mut args)?,
+        check: args.contains("--check"),
+        time: args.contains("--time"),
+        history: HistoryArgs {
+            compare: args.contains("--compare"),
+            baseline: args.opt_value_from_str("--baseline")?,
+            threshold: args
+                .opt_value_from_str::<_, f64>("--threshold")?
+                .map_or(0.1, |percent| percent / 100_f64),
+            name: args.opt_value_from_str("--save-baseline")?,
+        },
+        format: args
+            .opt_value_from_str("--format")?
+            .unwrap_or(OutputFormat::Text),
+        days: args
+            .opt_value_from_fn("--days", parse_days)?
+            .unwrap_or_default(),
+        config: RunConfig {
+            part: args.opt_value_from_fn("--part", advent_of_code::parse_part)?,
+            folder: "inputs",
+            jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+            part_timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+            day_timeout: args.opt_value_from_fn("--day-timeout", parse_duration)?,
+        },
+    })
+}
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+/// Parses a time limit such as `500ms`, `10s` or `2m`. Plain numbers are seconds.
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (value, unit) = match s.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(index) => s.split_at(index),
+        None => (s, "s"),
+    };
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration \"{s}\""))?;
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    let seconds = match unit {
+        "ms" => value / 1000_f64,
+        "s" => value,
+        "m" => value * 60_f64,
+        _ => {
+            return Err(format!(
+                "invalid duration unit in \"{s}\", expected ms, s or m"
+            ))
+        }
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration \"{s}\""))
+}
+
+/// Parses a selection of days such as `5`, `3..=7`, `3..8` or `1,3,10..=12`.
+fn parse_days(s: &str) -> Result<Vec<RangeInclusive<u8>>, String> {
+    let day = |d: &str| {
+        d.parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| format!("invalid day \"{d}\" in \"{s}\", expected 1 to 25"))
+    };
 
-            if is_empty {
-                0_f64
+    s.split(',')
+        .map(|selection| {
+            let days = if let Some((start, end)) = selection.split_once("..=") {
+                day(start)?..=day(end)?
+            } else if let Some((start, end)) = selection.split_once("..") {
+                day(start)?..=day(end)?.saturating_sub(1)
             } else {
-                advent_of_code::parse_exec_time(&output)
+                day(selection).map(|day| day..=day)?
+            };
+            match days.is_empty() {
+                true => Err(format!("empty range \"{selection}\" in \"{s}\"")),
+                false => Ok(days),
             }
         })
-        .sum();
+        .collect()
+}
+
+struct HistoryArgs {
+    compare: bool,
+    baseline: Option<String>,
+    threshold: f64,
+    name: Option<String>,
+}
+
+struct RunConfig {
+    folder: &'static str,
+    /// Only run this part of each day.
+    part: Option<u8>,
+    jobs: usize,
+    part_timeout: Option<Duration>,
+    day_timeout: Option<Duration>,
+}
+
+impl RunConfig {
+    fn parts(&self) -> Vec<u8> {
+        [1, 2]
+            .into_iter()
+            .filter(|part| self.part.is_none_or(|selected| selected == *part))
+            .collect()
+    }
+}
+
+/// The results of one day, kept until it is its turn to be printed.
+struct DayReport {
+    day: u8,
+    title: &'static str,
+    parse: Option<ParseResult>,
+    results: Vec<PartResult>,
+}
+
+/// A panic raised while running a day.
+struct Panic {
+    message: String,
+    overflow: bool,
+}
+
+thread_local! {
+    /// Whether this thread runs a day, set by `run_limited`.
+    static RUNS_DAY: Cell<bool> = const { Cell::new(false) };
+    /// Where the last panic on this thread was raised, recorded by the hook of `capture_panics`.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replaces the panic output of days with a record of where the panic happened, so that the
+/// runner can print it as part of the day's report. Other panics are printed as usual.
+fn capture_panics() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if RUNS_DAY.get() {
+            let location = info.location().map(|location| location.to_string());
+            PANIC_LOCATION.set(location);
+        } else {
+            default_hook(info);
+        }
+    }));
+}
+
+/// Runs `func` on its own thread and waits for at most `limit`. Returns `None` when the limit
+/// is hit; the thread is then abandoned and keeps running in the background until the runner
+/// exits. A panic in `func` is returned as `Some((Err(panic), elapsed))`.
+fn run_limited<T: Send + 'static>(
+    func: impl FnOnce() -> T + Send + 'static,
+    limit: Option<Duration>,
+) -> Option<(Result<T, Panic>, Duration)> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        RUNS_DAY.set(true);
+        let timer = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(func)).map_err(|payload| {
+            let message = advent_of_code::panic_message(&*payload).unwrap_or("no message");
+            Panic {
+                message: match PANIC_LOCATION.take() {
+                    Some(location) => format!("{message} ({location})"),
+                    None => message.to_string(),
+                },
+                overflow: advent_of_code::is_overflow(&*payload),
+            }
+        });
+        let elapsed = timer.elapsed();
+        let _ = sender.send((result, elapsed));
+    });
+
+    match limit {
+        Some(limit) => receiver.recv_timeout(limit).ok(),
+        None => receiver.recv().ok(),
+    }
+}
+
+fn run_part(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl FnOnce() -> Option<String> + Send + 'static,
+    limit: Option<Duration>,
+) -> PartResult {
+    match run_limited(solver, limit) {
+        Some((Ok(answer), elapsed)) => PartResult::new(year, day, part, answer, elapsed),
+        Some((Err(panic), elapsed)) if panic.overflow => {
+            PartResult::overflowed(year, day, part, elapsed)
+        }
+        Some((Err(panic), elapsed)) => {
+            PartResult::panicked(year, day, part, panic.message, elapsed)
+        }
+        None => PartResult::timed_out(year, day, part, limit.unwrap_or_default()),
+    }
+}
+
+fn run_day(solution: &'static dyn Solution, config: &RunConfig) -> DayReport {
+    let day = solution.day();
+
+    let (parse, results) = match advent_of_code::try_read_file(config.folder, solution.year(), day)
+    {
+        Ok(input) => run_parts(solution, input, config),
+        Err(_) => (
+            None,
+            missing_parts(solution.year(), day, PartStatus::MissingInput, config),
+        ),
+    };
+
+    DayReport {
+        day,
+        title: solution.title(),
+        parse,
+        results,
+    }
+}
+
+fn missing_parts(year: u16, day: u8, status: PartStatus, config: &RunConfig) -> Vec<PartResult> {
+    config
+        .parts()
+        .into_iter()
+        .map(|part| PartResult::missing(year, day, part, status))
+        .collect()
+}
+
+/// Days among `selected` that have an input but no `src/bin/<year>-<day>.rs` to solve it.
+fn unsolved_inputs(
+    year: u16,
+    selected: impl Fn(u8) -> bool,
+    days: &[&'static dyn Solution],
+    config: &RunConfig,
+) -> Vec<u8> {
+    (1..=25)
+        .filter(|day| selected(*day))
+        .filter(|day| !days.iter().any(|solution| solution.day() == *day))
+        .filter(|day| advent_of_code::try_read_file(config.folder, year, *day).is_ok())
+        .collect()
+}
+
+/// Parses the input once and runs the selected parts on it. Each part is limited to the part
+/// timeout, the parse phase and both parts together to the day timeout.
+fn run_parts(
+    solution: &'static dyn Solution,
+    input: String,
+    config: &RunConfig,
+) -> (Option<ParseResult>, Vec<PartResult>) {
+    let (year, day) = (solution.year(), solution.day());
+    let limit = |spent: Duration| match (config.part_timeout, config.day_timeout) {
+        (Some(part), Some(day)) => Some(part.min(day.saturating_sub(spent))),
+        (part, day) => part.or(day.map(|day| day.saturating_sub(spent))),
+    };
+
+    let parse_limit = limit(Duration::ZERO);
+    let (parsed, parse_elapsed) = match run_limited(move || solution.parse(&input), parse_limit) {
+        Some((parsed, elapsed)) => (parsed, elapsed),
+        None => {
+            let limit = parse_limit.unwrap_or_default();
+            let results = config
+                .parts()
+                .into_iter()
+                .map(|part| PartResult::timed_out(year, day, part, limit))
+                .collect();
+            return (Some(ParseResult::new(year, day, limit)), results);
+        }
+    };
+
+    let parse = solution
+        .has_parse()
+        .then(|| ParseResult::new(year, day, parse_elapsed));
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        // a panicking parse phase takes both parts down with it.
+        Err(panic) => {
+            let result = |part| match panic.overflow {
+                true => PartResult::overflowed(year, day, part, Duration::ZERO),
+                false => {
+                    PartResult::panicked(year, day, part, panic.message.clone(), Duration::ZERO)
+                }
+            };
+            return (parse, config.parts().into_iter().map(result).collect());
+        }
+    };
+
+    let mut spent = parse_elapsed;
+    let mut results = vec![];
+    for part in config.parts() {
+        let parsed = parsed.clone();
+        let result = match limit(spent) {
+            Some(limit) if limit.is_zero() => PartResult::timed_out(year, day, part, limit),
+            limit if part == 1 => run_part(year, day, 1, move || solution.part_one(&parsed), limit),
+            limit => run_part(year, day, 2, move || solution.part_two(&parsed), limit),
+        };
+        spent += result.elapsed();
+        results.push(result);
+    }
+
+    (parse, results)
+}
+
+/// Runs `days` on up to `config.jobs` threads and hands the reports to `report` in day order.
+/// Each part is timed on its own, so concurrent days do not count towards each other's timings.
+fn run_days(days: &[&'static dyn Solution], config: &RunConfig, mut report: impl FnMut(DayReport)) {
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..config.jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let next_day = &next_day;
+
+            scope.spawn(move || loop {
+                let index = next_day.fetch_add(1, Ordering::SeqCst);
+                match days.get(index) {
+                    Some(solution) => sender.send((index, run_day(*solution, config))).unwrap(),
+                    None => break,
+                }
+            });
+        }
+        drop(sender);
+
+        // days can finish out of order, hold on to them until all previous days are reported.
+        let mut finished = BTreeMap::new();
+        let mut next_report = 0;
+        for (index, day_report) in receiver {
+            finished.insert(index, day_report);
+            while let Some(day_report) = finished.remove(&next_report) {
+                report(day_report);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+fn print_day(report: &DayReport, format: OutputFormat) {
+    if format == OutputFormat::Json {
+        if let Some(parse) = &report.parse {
+            advent_of_code::print_parse_result(parse, format);
+        }
+        report
+            .results
+            .iter()
+            .for_each(|result| advent_of_code::print_result(result, format));
+        return;
+    }
+
+    let heading = match report.title {
+        "" => format!("| Day {:02} |", report.day),
+        title => format!("| Day {:02}: {title} |", report.day),
+    };
+
+    println!("{}", "-".repeat(heading.chars().count()));
+    println!("{ANSI_BOLD}{heading}{ANSI_RESET}");
+    println!("{}", "-".repeat(heading.chars().count()));
+
+    match report.results.first().map(|result| result.status) {
+        Some(PartStatus::MissingInput) => {
+            println!("No input.");
+            return;
+        }
+        Some(PartStatus::MissingSolution) => {
+            println!("No solution.");
+            return;
+        }
+        _ => {}
+    }
+
+    if let Some(parse) = &report.parse {
+        advent_of_code::print_parse_header();
+        advent_of_code::print_parse_result(parse, format);
+    }
+
+    for result in &report.results {
+        advent_of_code::print_part_header(result.part);
+        advent_of_code::print_result(result, format);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    // the readme table and the benchmark history describe runs of every day and part.
+    let partial = !args.days.is_empty() || args.config.part.is_some();
+    if partial && args.time {
+        eprintln!("`--time` runs every day, it cannot be combined with `--days` or `--part`.");
+        process::exit(1);
+    }
+
+    capture_panics();
+
+    let selected = |day: u8| args.days.is_empty() || args.days.iter().any(|r| r.contains(&day));
+    let days: Vec<&'static dyn Solution> = days::DAYS
+        .iter()
+        .copied()
+        .filter(|solution| solution.year() == args.year && selected(solution.day()))
+        .collect();
+
+    let missing_solution = |day: u8| DayReport {
+        day,
+        title: "",
+        parse: None,
+        results: missing_parts(args.year, day, PartStatus::MissingSolution, &args.config),
+    };
+
+    // days without a solution are reported in day order among the ones that ran.
+    let mut unsolved = unsolved_inputs(args.year, selected, &days, &args.config)
+        .into_iter()
+        .peekable();
+    let mut parses: Vec<ParseResult> = vec![];
+    let mut results: Vec<PartResult> = vec![];
+    run_days(&days, &args.config, |report| {
+        while let Some(day) = unsolved.next_if(|day| *day < report.day) {
+            print_day(&missing_solution(day), args.format);
+        }
+        print_day(&report, args.format);
+        parses.extend(report.parse);
+        results.extend(report.results);
+    });
+
+    for day in unsolved {
+        print_day(&missing_solution(day), args.format);
+    }
+
+    let total: Duration = parses.iter().map(ParseResult::elapsed).sum::<Duration>()
+        + results
+            .iter()
+            .filter(|result| result.solved)
+            .map(|result| result.elapsed())
+            .sum::<Duration>();
+
+    if args.format == OutputFormat::Text {
+        println!(
+            "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            total.as_secs_f64() * 1000_f64
+        );
+
+        print_parts_with("Timed out", PartStatus::TimedOut, &results);
+        print_parts_with("Overflowed", PartStatus::Overflowed, &results);
+        print_parts_with("Panicked", PartStatus::Panicked, &results);
+    }
+
+    // a crash is a failure, unlike a day that is not solved yet.
+    let crashed = results
+        .iter()
+        .any(|result| matches!(result.status, PartStatus::Panicked | PartStatus::Overflowed));
+
+    // parts that timed out keep running on abandoned threads and slow down every later day.
+    let timed_out = results
+        .iter()
+        .any(|result| result.status == PartStatus::TimedOut);
+    if timed_out && (args.time || !cfg!(debug_assertions)) {
+        eprintln!("Warning: timings of runs with timed out parts are not saved.");
+    }
+
+    if args.time && !timed_out {
+        update_readme_benchmarks(args.year, &parses, &results);
+    }
+
+    let regressions = update_benchmark_history(
+        args.year,
+        &parses,
+        &results,
+        args.history,
+        !partial && !timed_out,
+    );
+
+    if args.check {
+        check_answers(args.year, &results);
+    }
+
+    // exit explicitly: parts that timed out may still be running on abandoned threads.
+    process::exit(if regressions || crashed { 1 } else { 0 });
+}
+
+/// Lists the parts that ended with `status`, e.g. `Timed out: Day 03 part 2`.
+fn print_parts_with(label: &str, status: PartStatus, results: &[PartResult]) {
+    let parts: Vec<String> = results
+        .iter()
+        .filter(|result| result.status == status)
+        .map(|result| format!("Day {:02} part {}", result.day, result.part))
+        .collect();
+
+    if !parts.is_empty() {
+        println!("{ANSI_BOLD}{label}:{ANSI_RESET} {}", parts.join(", "));
+    }
+}
+
+fn update_readme_benchmarks(year: u16, parses: &[ParseResult], results: &[PartResult]) {
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: timings were measured in a debug build, run `cargo time` for release timings.");
+    }
+
+    match readme_benchmarks::update(year, parses, results) {
+        Ok(_) => eprintln!(
+            "🎄 Updated benchmarks in \"{}\".",
+            readme_benchmarks::README_PATH
+        ),
+        Err(e) => {
+            eprintln!("Failed to update benchmarks: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Appends complete release runs to the benchmark history and compares the run against a
+/// previous one if requested. Returns whether any part got slower than the threshold.
+fn update_benchmark_history(
+    year: u16,
+    parses: &[ParseResult],
+    results: &[PartResult],
+    args: HistoryArgs,
+    complete: bool,
+) -> bool {
+    let path = Path::new(benchmark_history::HISTORY_PATH);
+    let run = benchmark_history::Run::new(year, parses, results, args.name);
+
+    let mut regressions = false;
+
+    // stderr keeps `--format json` output one record per line.
+    if (args.compare || args.baseline.is_some()) && cfg!(debug_assertions) {
+        eprintln!("Warning: the benchmark history holds release runs, run with `--release` to compare against it.");
+    } else if args.compare || args.baseline.is_some() {
+        let runs = match benchmark_history::load(path, year) {
+            Ok(runs) => runs,
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {e}", path.display());
+                process::exit(1);
+            }
+        };
+
+        let baseline = match &args.baseline {
+            Some(name) => benchmark_history::find_baseline(&runs, name),
+            None => runs.last(),
+        };
+
+        match baseline {
+            Some(baseline) => {
+                let slower = benchmark_history::compare(baseline, &run, args.threshold);
+                eprintln!(
+                    "{ANSI_BOLD}Compared to {}:{ANSI_RESET} {} part(s) slower by more than {:.0}%",
+                    baseline.label(),
+                    slower.len(),
+                    args.threshold * 100_f64
+                );
+                for regression in &slower {
+                    eprintln!("{regression}");
+                }
+                regressions = !slower.is_empty();
+            }
+            None => eprintln!("No run to compare against in \"{}\".", path.display()),
+        }
+    }
+
+    if cfg!(debug_assertions) || !complete {
+        if run.name.is_some() {
+            eprintln!("Warning: only complete release runs are saved to the benchmark history.");
+        }
+    } else if let Err(e) = benchmark_history::append(path, &run) {
+        eprintln!("Failed to append to \"{}\": {e}", path.display());
+    }
+
+    regressions
+}
+
+fn check_answers(year: u16, results: &[PartResult]) {
+    let answers = match Answers::load(Path::new(answers::ANSWERS_PATH)) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {e}", answers::ANSWERS_PATH);
+            process::exit(1);
+        }
+    };
+
+    let mismatches = answers.check(year, results);
+    if mismatches.is_empty() {
+        eprintln!("🎄 All answers match \"{}\".", answers::ANSWERS_PATH);
+        return;
+    }
+
+    eprintln!("--- {}", answers::ANSWERS_PATH);
+    eprintln!("+++ current run");
+    for mismatch in &mismatches {
+        eprintln!("{mismatch}");
+    }
+    eprintln!(
+        "{} answer(s) differ from \"{}\".",
+        mismatches.len(),
+        answers::ANSWERS_PATH
+    );
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    struct FakeSolution {
+        day: u8,
+        delay: Duration,
+        has_parse: bool,
+    }
+
+    impl Solution for FakeSolution {
+        fn year(&self) -> u16 {
+            2022
+        }
+
+        fn day(&self) -> u8 {
+            self.day
+        }
+
+        fn title(&self) -> &'static str {
+            ""
+        }
+
+        fn has_parse(&self) -> bool {
+            self.has_parse
+        }
+
+        fn parse(&self, input: &str) -> advent_of_code::Parsed {
+            if self.has_parse {
+                thread::sleep(self.delay);
+            }
+            Arc::new(input.lines().count())
+        }
+
+        fn part_one(&self, _parsed: &advent_of_code::Parsed) -> Option<String> {
+            thread::sleep(self.delay);
+            Some(self.day.to_string())
+        }
+
+        fn part_two(&self, parsed: &advent_of_code::Parsed) -> Option<String> {
+            thread::sleep(self.delay);
+            if self.day == 6 {
+                panic!("day 6 crashed");
+            }
+            if self.day == 7 {
+                // overflows in builds with overflow checks, it wraps under `cargo test --release`.
+                return Some((u8::MAX + std::hint::black_box(self.day)).to_string());
+            }
+            parsed
+                .downcast_ref::<usize>()
+                .map(|lines| lines.to_string())
+        }
+    }
+
+    fn fake_days(delay: impl Fn(u8) -> u64) -> Vec<&'static dyn Solution> {
+        // days 1 to 7 have example files to use as input.
+        (1..=7)
+            .map(|day| {
+                let solution: &'static dyn Solution = Box::leak(Box::new(FakeSolution {
+                    day,
+                    delay: Duration::from_millis(delay(day)),
+                    has_parse: day % 2 == 0,
+                }));
+                solution
+            })
+            .collect()
+    }
+
+    fn config(jobs: usize) -> RunConfig {
+        RunConfig {
+            folder: "examples",
+            part: None,
+            jobs,
+            part_timeout: None,
+            day_timeout: None,
+        }
+    }
+
+    #[test]
+    fn test_run_days_reports_in_day_order() {
+        // later days finish first.
+        let days = fake_days(|day| 10 * (8 - day as u64));
+
+        for jobs in [1, 4] {
+            let mut reported = vec![];
+            run_days(&days, &config(jobs), |report| {
+                assert!(report.results[0].solved);
+                reported.push(report.day)
+            });
+            assert_eq!(reported, vec![1, 2, 3, 4, 5, 6, 7]);
+        }
+    }
+
+    #[test]
+    fn test_run_days_times_out_slow_parts() {
+        let days = fake_days(|day| if day == 3 { 10_000 } else { 0 });
+        let config = RunConfig {
+            part_timeout: Some(Duration::from_millis(50)),
+            ..config(2)
+        };
+
+        let mut statuses = vec![];
+        run_days(&days, &config, |report| {
+            statuses.extend(report.results.iter().map(|r| (r.day, r.status)))
+        });
+
+        assert_eq!(statuses.len(), 14);
+        assert!(statuses.contains(&(2, PartStatus::Solved)));
+        assert!(statuses.contains(&(3, PartStatus::TimedOut)));
+        assert!(statuses.contains(&(4, PartStatus::Solved)));
+    }
+
+    #[test]
+    fn test_day_timeout_is_shared_by_both_parts() {
+        let days = fake_days(|_| 60);
+        let config = RunConfig {
+            day_timeout: Some(Duration::from_millis(100)),
+            ..config(1)
+        };
+
+        let report = run_day(days[0], &config);
+        assert_eq!(report.results[0].status, PartStatus::Solved);
+        assert_eq!(report.results[1].status, PartStatus::TimedOut);
+
+        // day 2 spends part of its time in the parse phase, leaving too little for part one.
+        let report = run_day(days[1], &config);
+        assert!(report.parse.is_some());
+        assert_eq!(report.results[0].status, PartStatus::TimedOut);
+        assert_eq!(report.results[1].status, PartStatus::TimedOut);
+    }
+
+    #[test]
+    fn test_parse_is_shared_by_both_parts() {
+        let days = fake_days(|_| 0);
+
+        let report = run_day(days[0], &config(1));
+        assert_eq!(report.parse, None);
+
+        let report = run_day(days[1], &config(1));
+        assert!(report.parse.is_some());
+        let lines = advent_of_code::read_file("examples", 2022, 2)
+            .lines()
+            .count();
+        assert_eq!(report.results[1].answer, Some(lines.to_string()));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_overflow_is_reported_per_part() {
+        let days = fake_days(|_| 0);
+
+        let report = run_day(days[6], &config(1));
+        assert_eq!(report.results[0].status, PartStatus::Solved);
+        assert_eq!(report.results[1].status, PartStatus::Overflowed);
+        assert_eq!(report.results[1].answer, None);
+    }
+
+    #[test]
+    fn test_panic_is_reported_with_its_message() {
+        capture_panics();
+        let days = fake_days(|_| 0);
+
+        let report = run_day(days[5], &config(1));
+        assert_eq!(report.results[0].status, PartStatus::Solved);
+        assert_eq!(report.results[1].status, PartStatus::Panicked);
+
+        let error = report.results[1].error.as_deref().unwrap();
+        assert!(error.starts_with("day 6 crashed (src/main.rs:"), "{error}");
+    }
+
+    #[test]
+    fn test_missing_input_and_solution() {
+        let solution: &'static dyn Solution = Box::leak(Box::new(FakeSolution {
+            day: 25,
+            delay: Duration::ZERO,
+            has_parse: false,
+        }));
+        let report = run_day(solution, &config(1));
+        assert_eq!(report.results[0].status, PartStatus::MissingInput);
+
+        let days = fake_days(|_| 0);
+        assert_eq!(
+            unsolved_inputs(2022, |_| true, &days[..6], &config(1)),
+            vec![7, 8]
+        );
+        assert_eq!(
+            unsolved_inputs(2022, |day| day < 8, &days[..6], &config(1)),
+            vec![7]
+        );
+    }
+
+    #[test]
+    fn test_run_single_part() {
+        let days = fake_days(|_| 0);
+        let config = RunConfig {
+            part: Some(2),
+            ..config(1)
+        };
+
+        let report = run_day(days[1], &config);
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.results[0].part, 2);
+        assert!(report.results[0].solved);
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(vec![5..=5]));
+        assert_eq!(parse_days("3..=7"), Ok(vec![3..=7]));
+        assert_eq!(parse_days("3..8"), Ok(vec![3..=7]));
+        assert_eq!(parse_days("1,10..=12"), Ok(vec![1..=1, 10..=12]));
+        assert!(parse_days("0..=3").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("a..b").is_err());
+        assert!(parse_days("3..1").is_err());
+        assert!(parse_days("3..3").is_err());
+        assert!(parse_days("1,7..=6").is_err());
+    }
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("10"), Ok(Duration::from_secs(10)));
+        assert!(parse_duration("10h").is_err());
+        assert!(parse_duration("soon").is_err());
+    }
 }
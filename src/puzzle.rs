@@ -0,0 +1,430 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    aoc_client::{Client, ClientError},
+    input::day_path,
+    ANSI_BOLD, ANSI_RESET,
+};
+use regex::Regex;
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+};
+
+pub const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_CYAN: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+/// Bold and yellow, for the numbers and phrases the puzzle highlights.
+const ANSI_EMPHASIS: &str = "\x1b[1m\x1b[33m";
+
+/// Paragraphs are wrapped at the terminal width, but at most at this many columns.
+const MAX_WIDTH: usize = 100;
+
+/// Marks the start and end of a link's text while rendering a line.
+const LINK_START: char = '\u{1}';
+const LINK_END: char = '\u{2}';
+
+/// Splits a puzzle description into part one and, once it is unlocked, part two.
+pub fn split_parts(markdown: &str) -> (&str, Option<&str>) {
+    let Some(heading) = markdown.find(PART_TWO_HEADING) else {
+        return (markdown, None);
+    };
+    let line_start = markdown[..heading].rfind('\n').map_or(0, |index| index + 1);
+    (&markdown[..line_start], Some(&markdown[line_start..]))
+}
+
+/// The part of `new` that is not in `old`, e.g. part two after part one was solved. Lines are
+/// compared with a longest common subsequence; everything from the first to the last added
+/// line is returned, so that blank lines between added paragraphs are kept.
+pub fn new_section(old: &str, new: &str) -> Option<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // common[i][j]: length of the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut added = vec![];
+    let (mut i, mut j) = (0, 0);
+    while j < new.len() {
+        if i < old.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if i < old.len() && common[i + 1][j] >= common[i][j + 1] {
+            i += 1;
+        } else {
+            added.push(j);
+            j += 1;
+        }
+    }
+
+    let first = *added.iter().find(|j| !new[**j].trim().is_empty())?;
+    let last = *added.iter().rfind(|j| !new[**j].trim().is_empty())?;
+    Some(new[first..=last].join("\n"))
+}
+
+/// An example input from a puzzle description, with the answers the description gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Finds the example inputs in a puzzle description: the code blocks introduced by a paragraph
+/// that mentions a new example, e.g. "For example:" but not "In the example above", or every
+/// code block if none is. The answers of
+/// an example are the last highlighted code in each part, e.g. `` `*24000*` ``, between the
+/// example and the next one.
+pub fn examples(markdown: &str) -> Vec<Example> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let part_two = lines
+        .iter()
+        .position(|line| line.contains(PART_TWO_HEADING))
+        .unwrap_or(lines.len());
+
+    // (index of the opening fence, index of the closing fence, introduced as an example)
+    let mut blocks = vec![];
+    let mut open: Option<usize> = None;
+    for (index, line) in lines.iter().enumerate() {
+        if !line.starts_with("```") {
+            continue;
+        }
+        match open.take() {
+            Some(start) => {
+                let intro = lines[..start].iter().rfind(|line| !line.trim().is_empty());
+                let is_example = intro
+                    .map(|intro| intro.to_lowercase())
+                    .is_some_and(|intro| {
+                        intro.contains("example") && !intro.contains("example above")
+                    });
+                blocks.push((start, index, is_example));
+            }
+            None => open = Some(index),
+        }
+    }
+
+    if blocks.iter().any(|(_, _, is_example)| *is_example) {
+        blocks.retain(|(_, _, is_example)| *is_example);
+    }
+    blocks.retain(|(start, end, _)| end - start > 1);
+
+    let answers = Regex::new(r"`\*([^`*]+)\*`|\*`([^`*]+)`\*").unwrap();
+    // the answers between `from` and `to`, where an example after `to` has none in that part.
+    let answer = |from: usize, to: usize| {
+        lines[from..to.max(from)]
+            .iter()
+            .filter(|line| !line.starts_with("Your puzzle answer was"))
+            .flat_map(|line| answers.captures_iter(line))
+            .filter_map(|captures| captures.get(1).or(captures.get(2)))
+            .map(|answer| answer.as_str().to_string())
+            .last()
+    };
+
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(index, (start, end, _))| {
+            let next = blocks.get(index + 1).map_or(lines.len(), |block| block.0);
+            Example {
+                input: format!("{}\n", lines[start + 1..*end].join("\n").trim_end()),
+                part_one: answer(*end, next.min(part_two)),
+                part_two: answer((*end).max(part_two), next.max(part_two)),
+            }
+        })
+        .collect()
+}
+
+/// Downloads the puzzle description again, e.g. to pick up part two after part one was solved.
+/// Returns the section that was not in the previous download, if any.
+pub fn refresh(client: &Client, year: u16, day: u8) -> Result<Option<String>, ClientError> {
+    let old = fs::read_to_string(day_path("puzzles", year, day, "md")).unwrap_or_default();
+    let path = client.download_puzzle(year, day)?;
+    let new = fs::read_to_string(path).map_err(ClientError::IoError)?;
+    Ok(new_section(&old, &new))
+}
+
+fn is_underline(line: &str) -> bool {
+    line.len() >= 3 && line.chars().all(|c| c == '-')
+}
+
+/// The number of characters a line takes up in the terminal, without escape sequences.
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in text.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => width += 1,
+        }
+    }
+    width
+}
+
+/// Wraps styled text at `width` columns. The first line starts with `prefix`, the others are
+/// indented to line up with it.
+fn wrap(prefix: &str, text: &str, width: usize) -> String {
+    let indent = " ".repeat(visible_width(prefix));
+    let mut lines = vec![prefix.to_string()];
+    let mut line_width = visible_width(prefix);
+    let mut empty = true;
+
+    for word in text.split(' ').filter(|word| !word.is_empty()) {
+        let word_width = visible_width(word);
+        if !empty && line_width + 1 + word_width > width {
+            lines.push(indent.clone());
+            line_width = indent.len();
+            empty = true;
+        }
+        let line = lines.last_mut().unwrap();
+        if !empty {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+        empty = false;
+    }
+
+    lines.join("\n")
+}
+
+/// Replaces `*emphasis*`, `` `code` `` and `[links](...)` with terminal formatting.
+fn render_inline(line: &str, links: &Regex) -> String {
+    let line = links.replace_all(line, format!("{LINK_START}$1{LINK_END}"));
+
+    let mut rendered = String::new();
+    let mut styles: Vec<&str> = vec![];
+    let mut toggle = |rendered: &mut String, style: &'static str| {
+        match styles.iter().rposition(|s| *s == style) {
+            Some(index) => {
+                styles.remove(index);
+            }
+            None => styles.push(style),
+        }
+        rendered.push_str(ANSI_RESET);
+        styles.iter().for_each(|style| rendered.push_str(style));
+    };
+
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => rendered.extend(chars.next()),
+            '*' => toggle(&mut rendered, ANSI_EMPHASIS),
+            '`' => toggle(&mut rendered, ANSI_CYAN),
+            LINK_START | LINK_END => toggle(&mut rendered, ANSI_UNDERLINE),
+            c => rendered.push(c),
+        }
+    }
+    rendered.push_str(ANSI_RESET);
+    rendered
+}
+
+/// Renders a puzzle description in the markdown written by `cargo download` for the terminal:
+/// headings are bold, the highlighted `*numbers*` are bold and yellow, code is cyan and
+/// paragraphs are wrapped at `width` columns.
+pub fn render(markdown: &str, width: usize) -> String {
+    let links = Regex::new(r"\[([^\[\]]*)\]\([^)]*\)").unwrap();
+    let mut rendered = vec![];
+    let mut in_code = false;
+    let mut lines = markdown.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+
+        if in_code {
+            rendered.push(format!("    {ANSI_CYAN}{line}{ANSI_RESET}"));
+            continue;
+        }
+
+        let heading = match line.strip_prefix("## ") {
+            Some(heading) => Some(heading),
+            None if !line.is_empty() && lines.peek().is_some_and(|next| is_underline(next)) => {
+                lines.next();
+                Some(line)
+            }
+            None => None,
+        };
+
+        if line.is_empty() {
+            rendered.push(String::new());
+        } else if let Some(heading) = heading {
+            let heading = heading.trim_start_matches('\\');
+            rendered.push(format!("{ANSI_BOLD}{ANSI_GREEN}{heading}{ANSI_RESET}"));
+        } else if let Some(item) = line.strip_prefix("* ") {
+            rendered.push(wrap("  • ", &render_inline(item, &links), width));
+        } else {
+            rendered.push(wrap("", &render_inline(line, &links), width));
+        }
+    }
+
+    rendered.join("\n")
+}
+
+/// Puzzle markdown rendered at the terminal's width, or as is if the output is piped.
+fn for_output(markdown: &str, is_terminal: bool) -> String {
+    if !is_terminal {
+        return markdown.to_string();
+    }
+    let width = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .map_or(MAX_WIDTH, |columns: usize| columns.min(MAX_WIDTH));
+    render(markdown, width)
+}
+
+/// Prints puzzle markdown, rendered for the terminal unless the output is piped.
+pub fn print(markdown: &str) {
+    println!("{}", for_output(markdown, io::stdout().is_terminal()));
+}
+
+/// Like `print`, but to stderr, e.g. next to `--format json` output.
+pub fn eprint(markdown: &str) {
+    eprintln!("{}", for_output(markdown, io::stderr().is_terminal()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "\\--- Day 1: Calorie Counting ---\n----------\n\n\
+                          The [Elves](/2022) need *fifty stars*.\n\n\
+                          ```\n1000\n2000\n```\n\n\
+                          * The first Elf carries `*6000*` Calories.\n\n\
+                          \\--- Part Two ---\n----------\n\n\
+                          Find the *top three* Elves.";
+
+    #[test]
+    fn test_split_parts() {
+        let (part_one, part_two) = split_parts(PUZZLE);
+        assert!(part_one.starts_with("\\--- Day 1"));
+        assert!(!part_one.contains("Part Two"));
+        assert!(part_two.unwrap().starts_with("\\--- Part Two ---\n"));
+
+        assert_eq!(split_parts("\\--- Day 1 ---"), ("\\--- Day 1 ---", None));
+    }
+
+    #[test]
+    fn test_new_section() {
+        let (part_one, _) = split_parts(PUZZLE);
+        let solved = format!(
+            "{part_one}Your puzzle answer was `24000`.\n\n{}",
+            &PUZZLE[part_one.len()..]
+        );
+
+        let section = new_section(part_one, &solved).unwrap();
+        assert!(section.starts_with("Your puzzle answer was `24000`.\n\n\\--- Part Two ---"));
+        assert!(section.ends_with("Find the *top three* Elves."));
+
+        assert_eq!(new_section(PUZZLE, PUZZLE), None);
+        assert_eq!(new_section("", "a\n\nb"), Some("a\n\nb".into()));
+    }
+
+    #[test]
+    fn test_examples() {
+        let examples = examples(PUZZLE);
+        assert_eq!(
+            examples,
+            vec![Example {
+                input: "1000\n2000\n".into(),
+                part_one: Some("6000".into()),
+                part_two: None,
+            }]
+        );
+
+        let day_one = fs::read_to_string(day_path("puzzles", 2022, 1, "md")).unwrap();
+        let example = fs::read_to_string(day_path("examples", 2022, 1, "txt")).unwrap();
+        assert_eq!(
+            super::examples(&day_one),
+            vec![Example {
+                input: format!("{}\n", example.trim_end()),
+                part_one: Some("24000".into()),
+                part_two: Some("45000".into()),
+            }]
+        );
+
+        // the steps that follow an example are not examples themselves.
+        let day_five = fs::read_to_string(day_path("puzzles", 2022, 5, "md")).unwrap();
+        let examples = super::examples(&day_five);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].part_one.as_deref(), Some("CMZ"));
+        assert_eq!(examples[0].part_two, None);
+
+        // part two brings its own, larger example.
+        let two_examples =
+            "For example:\n\n```\nR 4\nU 4\n```\n\nThe tail visits `*13*` positions.\n\n\
+                            \\--- Part Two ---\n----------\n\n\
+                            The tail visits `*1*` position above. Here is a larger example:\n\n\
+                            ```\nR 5\nU 8\n```\n\nNow the tail visits `*36*` positions.";
+        assert_eq!(
+            super::examples(two_examples),
+            vec![
+                Example {
+                    input: "R 4\nU 4\n".into(),
+                    part_one: Some("13".into()),
+                    part_two: Some("1".into()),
+                },
+                Example {
+                    input: "R 5\nU 8\n".into(),
+                    part_one: None,
+                    part_two: Some("36".into()),
+                },
+            ]
+        );
+
+        let day_seven = fs::read_to_string(day_path("puzzles", 2022, 7, "md")).unwrap();
+        let examples = super::examples(&day_seven);
+        assert_eq!(examples.len(), 1);
+        assert!(examples[0].input.starts_with("$ cd /\n$ ls\n"));
+        assert_eq!(examples[0].part_one.as_deref(), Some("95437"));
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render(PUZZLE, 80);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "\x1b[1m\x1b[32m--- Day 1: Calorie Counting ---\x1b[0m"
+        );
+        assert_eq!(lines[1], "");
+        assert_eq!(
+            lines[2],
+            "The \x1b[0m\x1b[4mElves\x1b[0m need \x1b[0m\x1b[1m\x1b[33mfifty stars\x1b[0m.\x1b[0m"
+        );
+        assert_eq!(lines[4], "    \x1b[36m1000\x1b[0m");
+        assert_eq!(
+            lines[7],
+            "  • The first Elf carries \x1b[0m\x1b[36m\x1b[0m\x1b[36m\x1b[1m\x1b[33m6000\x1b[0m\x1b[36m\x1b[0m Calories.\x1b[0m"
+        );
+        assert_eq!(lines[9], "\x1b[1m\x1b[32m--- Part Two ---\x1b[0m");
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("", "aaa bbb ccc", 7), "aaa bbb\nccc");
+        assert_eq!(wrap("  • ", "aaa bbb", 9), "  • aaa\n    bbb");
+        // escape sequences do not count towards the width.
+        assert_eq!(
+            wrap("", "\x1b[1maaa\x1b[0m bbb", 7),
+            "\x1b[1maaa\x1b[0m bbb"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ParseResult, PartResult};
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path, time::Duration};
+
+pub const README_PATH: &str = "README.md";
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+pub enum BenchmarksError {
+    MarkersNotFound,
+    IoError(io::Error),
+}
+
+impl Display for BenchmarksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BenchmarksError::MarkersNotFound => {
+                write!(f, "could not find two \"{MARKER}\" markers in readme.")
+            }
+            BenchmarksError::IoError(e) => write!(f, "could not update readme: {e}"),
+        }
+    }
+}
+
+fn format_time(elapsed: Duration) -> String {
+    format!("`{elapsed:.1?}`")
+}
+
+/// Renders a markdown table of the timings of the parse phases and solved parts. The total
+/// counts the parse phases, like the total that `cargo all` prints.
+pub fn render_table(year: u16, parses: &[ParseResult], results: &[PartResult]) -> String {
+    // the parse phase, part 1 and part 2 of each day.
+    let mut days: BTreeMap<u8, [Option<Duration>; 3]> = BTreeMap::new();
+    for parse in parses {
+        days.entry(parse.day).or_default()[0] = Some(parse.elapsed());
+    }
+    for result in results.iter().filter(|result| result.solved) {
+        if let Some(time) = days
+            .entry(result.day)
+            .or_default()
+            .get_mut(usize::from(result.part))
+        {
+            *time = Some(result.elapsed());
+        }
+    }
+
+    let mut table = String::from("\n## Benchmarks\n\n");
+    table += "| Day | Parse | Part 1 | Part 2 |\n";
+    table += "| :---: | :---: | :---: | :---:  |\n";
+
+    let mut totals = [Duration::ZERO; 3];
+    for (day, times) in &days {
+        let cells: Vec<String> = times
+            .iter()
+            .zip(totals.iter_mut())
+            .map(|(time, total)| match time {
+                Some(time) => {
+                    *total += *time;
+                    format_time(*time)
+                }
+                None => "-".into(),
+            })
+            .collect();
+
+        table += &format!(
+            "| [Day {day}](./src/bin/{year}-{day:02}.rs) | {} | {} | {} |\n",
+            cells[0], cells[1], cells[2]
+        );
+    }
+
+    table += &format!(
+        "| **Total** | {} | {} | {} |\n",
+        format_time(totals[0]),
+        format_time(totals[1]),
+        format_time(totals[2])
+    );
+    table += &format!(
+        "\n**Total: {:.2}ms**\n",
+        totals.iter().sum::<Duration>().as_secs_f64() * 1000_f64
+    );
+
+    table
+}
+
+/// Replaces everything between the two benchmark markers in `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, BenchmarksError> {
+    let start = readme
+        .find(MARKER)
+        .ok_or(BenchmarksError::MarkersNotFound)?
+        + MARKER.len();
+    let end = readme[start..]
+        .find(MARKER)
+        .ok_or(BenchmarksError::MarkersNotFound)?
+        + start;
+
+    Ok(format!("{}{table}{}", &readme[..start], &readme[end..]))
+}
+
+pub fn update(
+    year: u16,
+    parses: &[ParseResult],
+    results: &[PartResult],
+) -> Result<(), BenchmarksError> {
+    let path = Path::new(README_PATH);
+    let readme = fs::read_to_string(path).map_err(BenchmarksError::IoError)?;
+    let readme = replace_table(&readme, &render_table(year, parses, results))?;
+    fs::write(path, readme).map_err(BenchmarksError::IoError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, micros: Option<u64>) -> PartResult {
+        PartResult::new(
+            2022,
+            day,
+            part,
+            micros.map(|_| "0".into()),
+            Duration::from_micros(micros.unwrap_or(0)),
+        )
+    }
+
+    #[test]
+    fn test_render_table() {
+        let results = [
+            result(1, 1, Some(20)),
+            result(1, 2, Some(30)),
+            result(2, 1, Some(1500)),
+            result(2, 2, None),
+            result(3, 1, None),
+            result(3, 2, None),
+        ];
+
+        assert_eq!(
+            render_table(2022, &[], &results),
+            "\n## Benchmarks\n\n\
+             | Day | Parse | Part 1 | Part 2 |\n\
+             | :---: | :---: | :---: | :---:  |\n\
+             | [Day 1](./src/bin/2022-01.rs) | - | `20.0µs` | `30.0µs` |\n\
+             | [Day 2](./src/bin/2022-02.rs) | - | `1.5ms` | - |\n\
+             | **Total** | `0.0ns` | `1.5ms` | `30.0µs` |\n\
+             \n**Total: 1.55ms**\n"
+        );
+
+        // the parse phase counts towards the total, also for days without a solved part.
+        let parses = [
+            ParseResult::new(2022, 2, Duration::from_micros(450)),
+            ParseResult::new(2022, 3, Duration::from_micros(100)),
+        ];
+        assert_eq!(
+            render_table(2022, &parses, &results),
+            "\n## Benchmarks\n\n\
+             | Day | Parse | Part 1 | Part 2 |\n\
+             | :---: | :---: | :---: | :---:  |\n\
+             | [Day 1](./src/bin/2022-01.rs) | - | `20.0µs` | `30.0µs` |\n\
+             | [Day 2](./src/bin/2022-02.rs) | `450.0µs` | `1.5ms` | - |\n\
+             | [Day 3](./src/bin/2022-03.rs) | `100.0µs` | - | - |\n\
+             | **Total** | `550.0µs` | `1.5ms` | `30.0µs` |\n\
+             \n**Total: 2.10ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# Readme\n{MARKER}\nold table\n{MARKER}\n## Footer\n");
+        assert_eq!(
+            replace_table(&readme, "\nnew table\n").ok(),
+            Some(format!(
+                "# Readme\n{MARKER}\nnew table\n{MARKER}\n## Footer\n"
+            ))
+        );
+
+        assert!(replace_table("# Readme\n", "\nnew table\n").is_err());
+    }
+}
@@ -0,0 +1,419 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    answers,
+    aoc_client::{Client, ClientError},
+    input::day_path,
+    puzzle, PartResult,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Reads the verdict from the server's response to a submission.
+    /// Returns `None` for responses that say nothing about the answer, e.g. rate limits.
+    pub fn from_response(response: &str) -> Option<Verdict> {
+        if response.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(Verdict::Incorrect)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Why an answer was not sent to the server.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    AlreadySubmitted(Verdict),
+    AboveTooHigh(String),
+    BelowTooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with \"{answer}\".")
+            }
+            Refusal::AlreadySubmitted(verdict) => {
+                write!(f, "this answer was already submitted and was {verdict}.")
+            }
+            Refusal::AboveTooHigh(bound) => {
+                write!(f, "\"{bound}\" was already too high.")
+            }
+            Refusal::BelowTooLow(bound) => {
+                write!(f, "\"{bound}\" was already too low.")
+            }
+        }
+    }
+}
+
+/// Every answer submitted for a day, together with the server's verdict.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> io::Result<History> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents + "\n")
+    }
+
+    /// Checks `answer` against earlier verdicts, refusing answers that are known to be wrong.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        let submissions = self.submissions.iter().filter(|s| s.part == part);
+
+        for submission in submissions {
+            if submission.verdict == Verdict::Correct {
+                return Err(Refusal::AlreadySolved(submission.answer.clone()));
+            }
+
+            if submission.answer == answer {
+                return Err(Refusal::AlreadySubmitted(submission.verdict));
+            }
+
+            match (
+                submission.verdict,
+                compare_numbers(answer, &submission.answer),
+            ) {
+                (Verdict::TooHigh, Some(Ordering::Greater | Ordering::Equal)) => {
+                    return Err(Refusal::AboveTooHigh(submission.answer.clone()));
+                }
+                (Verdict::TooLow, Some(Ordering::Less | Ordering::Equal)) => {
+                    return Err(Refusal::BelowTooLow(submission.answer.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        self.submissions.push(Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+        });
+    }
+}
+
+/// Compares two numeric answers of any integer type up to `u128`.
+/// Returns `None` if either is not a number, e.g. for block letters.
+fn compare_numbers(a: &str, b: &str) -> Option<Ordering> {
+    match (a.parse::<i128>(), b.parse::<i128>()) {
+        (Ok(a), Ok(b)) => Some(a.cmp(&b)),
+        _ => match (a.parse::<u128>(), b.parse::<u128>()) {
+            (Ok(a), Ok(b)) => Some(a.cmp(&b)),
+            // only one of them is above `i128::MAX`.
+            (Ok(_), Err(_)) if b.parse::<i128>().is_ok() => Some(Ordering::Greater),
+            (Err(_), Ok(_)) if a.parse::<i128>().is_ok() => Some(Ordering::Less),
+            _ => None,
+        },
+    }
+}
+
+pub fn history_path(year: u16, day: u8) -> PathBuf {
+    day_path("submissions", year, day, "json")
+}
+
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+    IoError(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "refusing to submit: {refusal}"),
+            SubmitError::Client(e) => write!(f, "{e}"),
+            SubmitError::IoError(e) => write!(f, "could not access submission history: {e}"),
+        }
+    }
+}
+
+/// Submits `answer` with `client` unless the history at `history_path` rules it out.
+/// Definite verdicts are recorded; `Ok(None)` means the server gave none (e.g. rate limit).
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    history_path: &Path,
+) -> Result<Option<Verdict>, SubmitError> {
+    let mut history = History::load(history_path).map_err(SubmitError::IoError)?;
+    history.check(part, answer).map_err(SubmitError::Refused)?;
+
+    let response = client
+        .submit(year, day, part, answer)
+        .map_err(SubmitError::Client)?;
+    // stderr keeps `--format json` output one record per line.
+    eprintln!("{response}");
+
+    let verdict = Verdict::from_response(&response);
+    if let Some(verdict) = verdict {
+        history.record(part, answer, verdict);
+        history.save(history_path).map_err(SubmitError::IoError)?;
+    }
+
+    Ok(verdict)
+}
+
+/// Submits the answer of a part run by `solve!`, exiting if it could not be submitted.
+pub fn submit_result(year: u16, result: &PartResult) {
+    let answer = match &result.answer {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Part {} is not solved, nothing to submit.", result.part);
+            process::exit(1);
+        }
+    };
+
+    if answer.contains('\n') {
+        eprintln!("Part {} returned a multi-line answer. Read block letters with \"advent_of_code::helpers::ocr::parse\" before submitting.", result.part);
+        process::exit(1);
+    }
+
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    match submit(
+        &client,
+        year,
+        result.day,
+        result.part,
+        answer,
+        &history_path(year, result.day),
+    ) {
+        Ok(Some(verdict)) => {
+            eprintln!("---");
+            eprintln!("🎄 Submitted \"{answer}\": {verdict}.");
+
+            if verdict == Verdict::Correct {
+                match answers::record(year, result.day, result.part, answer) {
+                    Ok(_) => eprintln!("🎄 Recorded answer in \"{}\".", answers::ANSWERS_PATH),
+                    Err(e) => eprintln!("Failed to record answer: {e}"),
+                }
+            }
+
+            // solving part one reveals part two.
+            if verdict == Verdict::Correct && result.part == 1 {
+                match puzzle::refresh(&client, year, result.day) {
+                    Ok(Some(section)) => {
+                        eprintln!("---");
+                        puzzle::eprint(&section);
+                    }
+                    Ok(None) => {}
+                    Err(e) => eprintln!("Failed to update the puzzle description: {e}"),
+                }
+            }
+        }
+        Ok(None) => {
+            eprintln!("---");
+            eprintln!("🎄 Submitted \"{answer}\", but the response did not contain a verdict.");
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(submissions: &[(u8, &str, Verdict)]) -> History {
+        let mut history = History::default();
+        for (part, answer, verdict) in submissions {
+            history.record(*part, answer, *verdict);
+        }
+        history
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too high."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Incorrect)
+        );
+        assert_eq!(
+            Verdict::from_response("You gave an answer too recently."),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_refuses_known_answers() {
+        let history = history(&[(1, "42", Verdict::Incorrect), (2, "7", Verdict::Correct)]);
+
+        assert_eq!(
+            history.check(1, "42"),
+            Err(Refusal::AlreadySubmitted(Verdict::Incorrect))
+        );
+        assert_eq!(
+            history.check(2, "8"),
+            Err(Refusal::AlreadySolved("7".into()))
+        );
+        assert_eq!(history.check(1, "43"), Ok(()));
+    }
+
+    #[test]
+    fn test_check_refuses_answers_outside_bounds() {
+        let history = history(&[(1, "100", Verdict::TooHigh), (1, "10", Verdict::TooLow)]);
+
+        assert_eq!(
+            history.check(1, "150"),
+            Err(Refusal::AboveTooHigh("100".into()))
+        );
+        assert_eq!(
+            history.check(1, "5"),
+            Err(Refusal::BelowTooLow("10".into()))
+        );
+        assert_eq!(history.check(1, "50"), Ok(()));
+        assert_eq!(history.check(2, "150"), Ok(()));
+        assert_eq!(history.check(1, "CMZ"), Ok(()));
+    }
+
+    #[test]
+    fn test_check_bounds_of_wide_answers() {
+        let max = u128::MAX.to_string();
+        let history = history(&[(1, "-5", Verdict::TooLow), (2, &max, Verdict::TooHigh)]);
+
+        assert_eq!(
+            history.check(1, "-6"),
+            Err(Refusal::BelowTooLow("-5".into()))
+        );
+        assert_eq!(history.check(1, &max), Ok(()));
+        assert_eq!(
+            history.check(2, &max),
+            Err(Refusal::AlreadySubmitted(Verdict::TooHigh))
+        );
+        assert_eq!(history.check(2, &(u128::MAX - 1).to_string()), Ok(()));
+        assert_eq!(history.check(2, "-1"), Ok(()));
+    }
+
+    #[test]
+    fn test_submit_with_stub_server() {
+        use crate::aoc_client::stub;
+        use std::env;
+
+        let dir = env::temp_dir().join(format!("aoc-submit-test-{}", process::id()));
+        let history_path = dir.join("submissions").join("07.json");
+
+        // the second answer is refused locally, so the server only sees two submissions.
+        let (base_url, requests) = stub::serve(vec![
+            (
+                200,
+                "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>".into(),
+            ),
+            (
+                200,
+                "<main><article><p>That's the right answer!</p></article></main>".into(),
+            ),
+        ]);
+        let client = Client::new(&base_url, "abc123");
+
+        assert_eq!(
+            submit(&client, 2022, 7, 2, "150", &history_path).ok(),
+            Some(Some(Verdict::TooHigh))
+        );
+        assert!(matches!(
+            submit(&client, 2022, 7, 2, "200", &history_path),
+            Err(SubmitError::Refused(Refusal::AboveTooHigh(_)))
+        ));
+        assert_eq!(
+            submit(&client, 2022, 7, 2, "90", &history_path).ok(),
+            Some(Some(Verdict::Correct))
+        );
+
+        let bodies: Vec<String> = requests
+            .iter()
+            .map(|request| request.lines().last().unwrap_or("").to_string())
+            .collect();
+        assert_eq!(bodies, vec!["level=2&answer=150", "level=2&answer=90"]);
+
+        assert_eq!(
+            History::load(&history_path).unwrap(),
+            history(&[(2, "150", Verdict::TooHigh), (2, "90", Verdict::Correct)])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}